@@ -0,0 +1,244 @@
+use anyhow::{bail, Result};
+use ndarray::Array1;
+use ndarray_rand::rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// Critical value of the chi-square distribution with 3 degrees of freedom at the 5% level
+const CHI_SQUARE_CRITICAL: f64 = 7.815;
+
+/// Seeds of the order in which tied values of each variable are ranked
+const X_SEED: u64 = 0;
+const Y_SEED: u64 = 1;
+
+/// Ranks of a set of samples, with ties ranked in a random order drawn from a seed
+///
+/// Each variable must use its own seed, otherwise tied values of both would be ranked in the
+/// same order and appear dependent.
+fn ranks(x: &Array1<f64>, seed: u64) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..x.len()).collect();
+    order.shuffle(&mut StdRng::seed_from_u64(seed));
+    // the sort is stable so ties keep their shuffled order
+    order.sort_by(|a, b| x[*a].total_cmp(&x[*b]));
+    let mut ranks = vec![0; x.len()];
+    for (rank, idx) in order.into_iter().enumerate() {
+        ranks[idx] = rank;
+    }
+    ranks
+}
+
+/// Rectangle `[x0, x1) × [y0, y1)` of the rank plane and the samples it holds
+struct Cell {
+    x: (usize, usize),
+    y: (usize, usize),
+    points: Vec<(usize, usize)>,
+}
+
+impl Cell {
+    /// Splits the cell at the midpoint of both rank ranges into four quadrants
+    fn quadrants(&self) -> [Cell; 4] {
+        let xm = (self.x.0 + self.x.1) / 2;
+        let ym = (self.y.0 + self.y.1) / 2;
+        let mut quadrants = [
+            (self.x.0, xm, self.y.0, ym),
+            (xm, self.x.1, self.y.0, ym),
+            (self.x.0, xm, ym, self.y.1),
+            (xm, self.x.1, ym, self.y.1),
+        ]
+        .map(|(x0, x1, y0, y1)| Cell {
+            x: (x0, x1),
+            y: (y0, y1),
+            points: Vec::new(),
+        });
+        for (px, py) in &self.points {
+            let idx = usize::from(*px >= xm) + 2 * usize::from(*py >= ym);
+            quadrants[idx].points.push((*px, *py));
+        }
+        quadrants
+    }
+
+    /// Chi-square statistic of the quadrant counts against conditional uniformity, under which
+    /// each quadrant holds a share of the samples proportional to its area
+    ///
+    /// The areas differ between quadrants when a rank range has an odd width.
+    fn chi_square(&self, quadrants: &[Cell; 4]) -> f64 {
+        let count = self.points.len() as f64;
+        let area = ((self.x.1 - self.x.0) * (self.y.1 - self.y.0)) as f64;
+        quadrants
+            .iter()
+            .map(|q| {
+                let expected = count * ((q.x.1 - q.x.0) * (q.y.1 - q.y.0)) as f64 / area;
+                (q.points.len() as f64 - expected).powi(2) / expected
+            })
+            .sum()
+    }
+}
+
+/// # Adaptive Partitioning Mutual Information
+/// <https://doi.org/10.1109/18.761290>
+///
+/// Calculates the Darbellay–Vajda (1999) adaptive partitioning estimate of the mutual
+/// information between two continuous random variables measured in nats.
+///
+/// Unlike binning with [`hist2d()`](crate::hist2d()) this needs no bin count. Both variables are
+/// rank-transformed so that their marginals are equiquantized, and the rank plane is split
+/// recursively into quadrants at the middle of each cell's ranges. A cell is only split further
+/// while its quadrant counts reject conditional uniformity under a chi-square test with three
+/// degrees of freedom at the 5% level. Over the final cells, with `n(c)` samples spanning
+/// `nx(c)` and `ny(c)` ranks:
+/// ```math
+/// I(X;Y) = Σ n(c)/N ln[ N n(c) / (nx(c) ny(c)) ]
+/// ```
+///
+/// The whole plane is always split once. Tied values are ranked in a random order drawn
+/// independently for each variable from a fixed seed, so that ties add no spurious dependence
+/// and the estimate is reproducible.
+///
+/// # Errors
+/// If the arrays are not of equal size, have fewer than two samples or contain non-finite
+/// values.
+///
+/// # Usage
+/// ```
+/// use ndarray::Array1;
+/// use ndarray_rand::{RandomExt, rand_distr::StandardNormal};
+/// use information::adaptive_mutual_information;
+///
+/// let rho: f64 = 0.8;
+/// let x = Array1::<f64>::random(5000, StandardNormal);
+/// let e = Array1::<f64>::random(5000, StandardNormal);
+/// let y = &x * rho + &e * (1.0 - rho * rho).sqrt();
+/// let expected = -0.5 * (1.0 - rho * rho).ln();
+///
+/// let mi = adaptive_mutual_information(&x, &y).unwrap();
+/// assert!((mi - expected).abs() < 0.1);
+/// ```
+pub fn adaptive_mutual_information(x: &Array1<f64>, y: &Array1<f64>) -> Result<f64> {
+    if x.len() != y.len() {
+        bail!("Provided arrays must be of equal size");
+    }
+    if x.len() < 2 {
+        bail!("At least two samples are required");
+    }
+    if x.iter().chain(y.iter()).any(|v| !v.is_finite()) {
+        bail!("Provided samples must be finite");
+    }
+    let n = x.len();
+    let points = ranks(x, X_SEED).into_iter().zip(ranks(y, Y_SEED)).collect();
+    let root = Cell {
+        x: (0, n),
+        y: (0, n),
+        points,
+    };
+
+    let n_f = n as f64;
+    let mut mi = 0.0;
+    let mut stack: Vec<Cell> = root.quadrants().into();
+    while let Some(cell) = stack.pop() {
+        let count = cell.points.len();
+        if count == 0 {
+            continue;
+        }
+        let (wx, wy) = (cell.x.1 - cell.x.0, cell.y.1 - cell.y.0);
+        if wx >= 2 && wy >= 2 {
+            let quadrants = cell.quadrants();
+            let statistic = cell.chi_square(&quadrants);
+            if statistic > CHI_SQUARE_CRITICAL {
+                stack.extend(quadrants);
+                continue;
+            }
+        }
+        let count = count as f64;
+        mi += count / n_f * (n_f * count / (wx * wy) as f64).ln();
+    }
+    Ok(mi)
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{adaptive_mutual_information, ranks, Cell};
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1};
+    use ndarray_rand::{
+        rand_distr::{StandardNormal, Uniform},
+        RandomExt,
+    };
+
+    const N_SAMPLES: usize = 5000;
+
+    #[test]
+    fn test_ranks() {
+        let r = ranks(&array![0.5, -1.0, 3.0, 0.5], 0);
+        assert_eq!((r[1], r[2]), (0, 3));
+        assert_eq!([r[0].min(r[3]), r[0].max(r[3])], [1, 2]);
+    }
+
+    #[test]
+    fn test_chi_square_odd_width() {
+        // one sample per rank pair of a 3 x 3 cell is uniform despite the unequal quadrants
+        let cell = Cell {
+            x: (0, 3),
+            y: (0, 3),
+            points: (0..3).flat_map(|x| (0..3).map(move |y| (x, y))).collect(),
+        };
+        let quadrants = cell.quadrants();
+        let counts: Vec<usize> = quadrants.iter().map(|q| q.points.len()).collect();
+        assert_eq!(counts, vec![1, 2, 2, 4]);
+        assert_relative_eq!(cell.chi_square(&quadrants), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_independent_ties() {
+        // rounded samples tie often, which must not read as dependence
+        let x = Array1::<f64>::random(N_SAMPLES, StandardNormal).mapv(f64::round);
+        let y = Array1::<f64>::random(N_SAMPLES, StandardNormal).mapv(f64::round);
+        assert!(adaptive_mutual_information(&x, &y).unwrap().abs() < 0.01);
+
+        let x = Array1::random(N_SAMPLES, Uniform::new(0, 3)).mapv(|v| v as f64);
+        let y = Array1::random(N_SAMPLES, Uniform::new(0, 3)).mapv(|v| v as f64);
+        assert!(adaptive_mutual_information(&x, &y).unwrap().abs() < 0.01);
+    }
+
+    #[test]
+    fn test_gaussian() {
+        for rho in [0.3, 0.6, 0.9] {
+            let x = Array1::<f64>::random(N_SAMPLES, StandardNormal);
+            let e = Array1::<f64>::random(N_SAMPLES, StandardNormal);
+            let y = &x * rho + &e * (1.0 - rho * rho).sqrt();
+            let mi = adaptive_mutual_information(&x, &y).unwrap();
+            assert_relative_eq!(mi, -0.5 * (1.0 - rho * rho).ln(), epsilon = 0.1);
+        }
+    }
+
+    #[test]
+    fn test_independent() {
+        let x = Array1::random(N_SAMPLES, Uniform::new(0.0, 1.0));
+        let y = Array1::random(N_SAMPLES, Uniform::new(0.0, 1.0));
+        let mi = adaptive_mutual_information(&x, &y).unwrap();
+        assert!(mi.abs() < 0.01);
+    }
+
+    #[test]
+    fn test_nonlinear() {
+        // a dependence with no linear correlation is still detected
+        let x = Array1::random(N_SAMPLES, Uniform::new(-1.0, 1.0));
+        let e = Array1::random(N_SAMPLES, Uniform::new(-0.1, 0.1));
+        let y = x.mapv(|v| v * v) + e;
+        assert!(adaptive_mutual_information(&x, &y).unwrap() > 0.5);
+    }
+
+    #[test]
+    fn test_marginal_invariance() {
+        let x = Array1::<f64>::random(N_SAMPLES, StandardNormal);
+        let y = &x + &Array1::<f64>::random(N_SAMPLES, StandardNormal);
+        let mi = adaptive_mutual_information(&x, &y).unwrap();
+        let mi_t = adaptive_mutual_information(&x.mapv(f64::exp), &y.mapv(|v| v.powi(3))).unwrap();
+        assert_eq!(mi, mi_t);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(adaptive_mutual_information(&array![1.0, 2.0], &array![1.0]).is_err());
+        assert!(adaptive_mutual_information(&array![1.0], &array![1.0]).is_err());
+        assert!(adaptive_mutual_information(&array![1.0, f64::NAN], &array![1.0, 2.0]).is_err());
+    }
+}
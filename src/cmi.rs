@@ -1,3 +1,4 @@
+use crate::unit::Unit;
 use ndarray::{Array3, Axis, Zip};
 
 /// # Conditional Mutual Information
@@ -27,24 +28,56 @@ use ndarray::{Array3, Axis, Zip};
 /// // Measures: I(X;Y|Z) >= 0
 /// assert!(cmi >= 0.0);
 /// ```
+///
+/// Also accepts a three-dimensional [`SparseHist`](crate::SparseHist) of counts.
 #[must_use]
-pub fn conditional_mutual_information(p_xyz: &Array3<f64>) -> f64 {
-    let p_xz = p_xyz.sum_axis(Axis(1));
-    let p_yz = p_xyz.sum_axis(Axis(0));
-    let p_z = p_xz.sum_axis(Axis(0));
-
-    Zip::from(p_xyz)
-        .and_broadcast(&p_xz.insert_axis(Axis(1)))
-        .and_broadcast(&p_yz)
-        .and_broadcast(&p_z)
-        .fold(0.0, |acc, xyz, xz, yz, z| {
-            if *xyz == 0.0 || *xz == 0.0 || *yz == 0.0 || *z == 0.0 {
-                acc
-            } else {
-                // println!(">> {} {} {} {}", xyz, xz, yz, z);
-                acc + (xyz * ((z * xyz) / (xz * yz)).ln())
-            }
-        })
+pub fn conditional_mutual_information<P: ConditionalMutualInformation + ?Sized>(p_xyz: &P) -> f64 {
+    p_xyz.conditional_mutual_information()
+}
+
+/// Calculates the [`conditional_mutual_information()`] measured in the provided [`Unit`].
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{conditional_mutual_information_in, Unit};
+///
+/// // X and Y are copies of each other and independent of Z
+/// let p_xyz = array![[[0.25, 0.25], [0.0, 0.0]], [[0.0, 0.0], [0.25, 0.25]]];
+/// assert_eq!(conditional_mutual_information_in(&p_xyz, Unit::Bits), 1.0);
+/// ```
+#[must_use]
+pub fn conditional_mutual_information_in<P: ConditionalMutualInformation + ?Sized>(
+    p_xyz: &P,
+    unit: Unit,
+) -> f64 {
+    unit.convert(p_xyz.conditional_mutual_information())
+}
+
+/// Distributions accepted by [`conditional_mutual_information()`]
+pub trait ConditionalMutualInformation {
+    /// Calculates the conditional mutual information `I(X;Y|Z)` measured in nats.
+    fn conditional_mutual_information(&self) -> f64;
+}
+
+impl ConditionalMutualInformation for Array3<f64> {
+    fn conditional_mutual_information(&self) -> f64 {
+        let p_xz = self.sum_axis(Axis(1));
+        let p_yz = self.sum_axis(Axis(0));
+        let p_z = p_xz.sum_axis(Axis(0));
+
+        Zip::from(self)
+            .and_broadcast(&p_xz.insert_axis(Axis(1)))
+            .and_broadcast(&p_yz)
+            .and_broadcast(&p_z)
+            .fold(0.0, |acc, xyz, xz, yz, z| {
+                if *xyz == 0.0 || *xz == 0.0 || *yz == 0.0 || *z == 0.0 {
+                    acc
+                } else {
+                    acc + (xyz * ((z * xyz) / (xz * yz)).ln())
+                }
+            })
+    }
 }
 
 #[cfg(test)]
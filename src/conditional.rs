@@ -1,3 +1,4 @@
+use crate::unit::Unit;
 use ndarray::{Array2, Axis, Zip};
 
 /// # Conditional Entropy
@@ -20,17 +21,46 @@ use ndarray::{Array2, Axis, Zip};
 /// let hx = conditional_entropy(&p_xy);
 /// assert_eq!(hx, 0.4773856262211097);
 /// ```
+///
+/// Also accepts a two-dimensional [`SparseHist`](crate::SparseHist) of counts.
 #[must_use]
-pub fn conditional_entropy(p_xy: &Array2<f64>) -> f64 {
-    Zip::from(p_xy)
-        .and_broadcast(&p_xy.sum_axis(Axis(0)))
-        .fold(0.0, |acc, xy, y| {
-            if *xy == 0. || *y == 0. {
-                acc
-            } else {
-                acc - (xy * (xy / y).ln())
-            }
-        })
+pub fn conditional_entropy<P: ConditionalEntropy + ?Sized>(p_xy: &P) -> f64 {
+    p_xy.conditional_entropy()
+}
+
+/// Calculates the [`conditional_entropy()`] measured in the provided [`Unit`].
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{conditional_entropy_in, Unit};
+///
+/// let p_xy = array![[0.25, 0.25], [0.25, 0.25]];
+/// assert_eq!(conditional_entropy_in(&p_xy, Unit::Bits), 1.0);
+/// ```
+#[must_use]
+pub fn conditional_entropy_in<P: ConditionalEntropy + ?Sized>(p_xy: &P, unit: Unit) -> f64 {
+    unit.convert(p_xy.conditional_entropy())
+}
+
+/// Distributions accepted by [`conditional_entropy()`]
+pub trait ConditionalEntropy {
+    /// Calculates the conditional entropy `H(X|Y)` measured in nats.
+    fn conditional_entropy(&self) -> f64;
+}
+
+impl ConditionalEntropy for Array2<f64> {
+    fn conditional_entropy(&self) -> f64 {
+        Zip::from(self)
+            .and_broadcast(&self.sum_axis(Axis(0)))
+            .fold(0.0, |acc, xy, y| {
+                if *xy == 0. || *y == 0. {
+                    acc
+                } else {
+                    acc - (xy * (xy / y).ln())
+                }
+            })
+    }
 }
 
 #[cfg(test)]
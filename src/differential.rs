@@ -0,0 +1,116 @@
+use crate::{
+    knn::{validate_samples, KdTree, Metric},
+    special::{digamma, ln_gamma},
+};
+use anyhow::{bail, Result};
+use ndarray::Array2;
+use std::f64::consts::{LN_2, PI};
+
+/// Natural logarithm of the volume of the `d`-dimensional unit ball of a metric
+pub(crate) fn ln_unit_ball(dim: usize, metric: Metric) -> f64 {
+    let d = dim as f64;
+    match metric {
+        Metric::Chebyshev => d * LN_2,
+        Metric::Euclidean => d / 2.0 * PI.ln() - ln_gamma(d / 2.0 + 1.0),
+    }
+}
+
+/// # Kozachenko–Leonenko Entropy
+/// <https://en.wikipedia.org/wiki/Entropy_estimation#Estimators_based_on_nearest-neighbours>
+///
+/// Calculates the Kozachenko–Leonenko `k`-nearest-neighbour estimate of the differential entropy
+/// of a continuous random variable measured in nats.
+///
+/// This is the continuous counterpart of [`entropy()`](crate::entropy()). The matrix holds one
+/// sample per row and one dimension per column. With `ε(i)` the distance from sample `i` to its
+/// `k`-th nearest neighbour and `Vd` the volume of the `d`-dimensional unit ball of the chosen
+/// [`Metric`], the entropy is calculated as follows:
+/// ```math
+/// H(X) = ψ(N) - ψ(k) + ln[ Vd ] + d/N Σ ln[ ε(i) ]
+/// ```
+///
+/// # Errors
+/// If the matrix has no columns, if `k` is zero or not smaller than the number of samples, or if
+/// a sample coincides with its `k`-th neighbour.
+///
+/// # Usage
+/// ```
+/// use ndarray::Array2;
+/// use ndarray_rand::{RandomExt, rand_distr::StandardNormal};
+/// use information::{kozachenko_leonenko_entropy, Metric};
+/// use std::f64::consts::{E, PI};
+///
+/// let x = Array2::<f64>::random((2000, 1), StandardNormal);
+/// let expected = 0.5 * (2.0 * PI * E).ln();
+///
+/// let h = kozachenko_leonenko_entropy(&x, 3, Metric::Euclidean).unwrap();
+/// assert!((h - expected).abs() < 0.1);
+/// ```
+pub fn kozachenko_leonenko_entropy(x: &Array2<f64>, k: usize, metric: Metric) -> Result<f64> {
+    let n = validate_samples(&[x], k)?;
+    let tree = KdTree::new(x, metric);
+    let mut sum_ln_eps = 0.0;
+    for i in 0..n {
+        let eps = tree.nearest(i, k)[k - 1].0;
+        if eps == 0.0 {
+            bail!("Samples must be distinct from their k-th nearest neighbour");
+        }
+        sum_ln_eps += eps.ln();
+    }
+    let d = x.ncols() as f64;
+    let n_f = n as f64;
+    Ok(digamma(n_f) - digamma(k as f64) + ln_unit_ball(x.ncols(), metric) + d * sum_ln_eps / n_f)
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::kozachenko_leonenko_entropy;
+    use crate::knn::Metric;
+    use approx::assert_relative_eq;
+    use ndarray::Array2;
+    use ndarray_rand::{
+        rand_distr::{StandardNormal, Uniform},
+        RandomExt,
+    };
+    use std::f64::consts::{E, PI};
+
+    const N_SAMPLES: usize = 2000;
+    const METRICS: [Metric; 2] = [Metric::Chebyshev, Metric::Euclidean];
+
+    #[test]
+    fn test_gaussian() {
+        for dim in 1..=3 {
+            let x = Array2::<f64>::random((N_SAMPLES, dim), StandardNormal);
+            let sigma = 2.0;
+            let x = x * sigma;
+
+            // H = d/2 ln[ 2πe σ² ]
+            let expected = dim as f64 / 2.0 * (2.0 * PI * E * sigma * sigma).ln();
+            for metric in METRICS {
+                let h = kozachenko_leonenko_entropy(&x, 4, metric).unwrap();
+                assert_relative_eq!(h, expected, epsilon = 0.1 * dim as f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_uniform() {
+        for (dim, width) in [(1, 1.0), (1, 5.0), (2, 0.5)] {
+            let x = Array2::random((N_SAMPLES, dim), Uniform::new(0.0, width));
+
+            // H = d ln[ width ]
+            let expected = dim as f64 * f64::ln(width);
+            for metric in METRICS {
+                let h = kozachenko_leonenko_entropy(&x, 4, metric).unwrap();
+                assert_relative_eq!(h, expected, epsilon = 0.1 * dim as f64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let x = Array2::zeros((10, 1));
+        assert!(kozachenko_leonenko_entropy(&x, 3, Metric::Euclidean).is_err());
+    }
+}
@@ -0,0 +1,500 @@
+use crate::entropy::entropy;
+use anyhow::{bail, Result};
+use ndarray::Array1;
+
+/// # Bin Rule
+/// <https://en.wikipedia.org/wiki/Histogram#Number_of_bins_and_width>
+///
+/// Rule choosing the number of bins `k` for `n` samples spanning a range `R`.
+///
+/// * [`Sturges`](BinRule::Sturges): `k = ⌈log2(n)⌉ + 1`.
+/// * [`Scott`](BinRule::Scott): bins of width `h = 3.49 σ n^(-1/3)`, so `k = ⌈R / h⌉`.
+/// * [`FreedmanDiaconis`](BinRule::FreedmanDiaconis): bins of width `h = 2 IQR n^(-1/3)`, so
+///   `k = ⌈R / h⌉`.
+/// * [`Doane`](BinRule::Doane): Sturges' rule corrected for the skewness `g1` of the samples,
+///   `k = ⌈1 + log2(n) + log2(1 + |g1| / σg1)⌉`.
+/// * [`Sqrt`](BinRule::Sqrt): `k = ⌈√n⌉`.
+///
+/// The rules based on a bin width use a single bin when the width is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BinRule {
+    #[default]
+    Sturges,
+    Scott,
+    FreedmanDiaconis,
+    Doane,
+    Sqrt,
+}
+
+impl BinRule {
+    /// Returns the number of bins the rule chooses for a set of samples
+    ///
+    /// # Errors
+    /// If the samples are empty or contain non-finite values.
+    ///
+    /// # Usage
+    /// ```
+    /// use ndarray::Array1;
+    /// use information::BinRule;
+    ///
+    /// let x = Array1::range(0.0, 100.0, 1.0);
+    /// assert_eq!(BinRule::Sturges.nbins(&x).unwrap(), 8);
+    /// assert_eq!(BinRule::Sqrt.nbins(&x).unwrap(), 10);
+    /// ```
+    pub fn nbins(&self, x: &Array1<f64>) -> Result<usize> {
+        validate(x)?;
+        let n = x.len() as f64;
+        let (min, max) = range(x);
+        let from_width = |width: f64| {
+            if width > 0.0 {
+                ((max - min) / width).ceil().max(1.0) as usize
+            } else {
+                1
+            }
+        };
+        let nbins = match self {
+            Self::Sturges => n.log2().ceil() as usize + 1,
+            Self::Sqrt => n.sqrt().ceil() as usize,
+            Self::Scott => from_width(3.49 * x.std(0.0) * n.powf(-1.0 / 3.0)),
+            Self::FreedmanDiaconis => {
+                let sorted = sorted(x);
+                let iqr = quantile(&sorted, 0.75) - quantile(&sorted, 0.25);
+                from_width(2.0 * iqr * n.powf(-1.0 / 3.0))
+            }
+            Self::Doane => {
+                let sigma = x.std(0.0);
+                let correction = if x.len() > 2 && sigma > 0.0 {
+                    let mean = x.mean().unwrap_or_default();
+                    let skew = x
+                        .mapv(|v| ((v - mean) / sigma).powi(3))
+                        .mean()
+                        .unwrap_or_default();
+                    let sigma_g1 = (6.0 * (n - 2.0) / ((n + 1.0) * (n + 3.0))).sqrt();
+                    (1.0 + skew.abs() / sigma_g1).log2()
+                } else {
+                    0.0
+                };
+                (1.0 + n.log2() + correction).ceil() as usize
+            }
+        };
+        Ok(nbins.max(1))
+    }
+}
+
+/// Validates a set of samples to discretize
+fn validate(x: &Array1<f64>) -> Result<()> {
+    if x.is_empty() {
+        bail!("Provided samples must not be empty");
+    }
+    if x.iter().any(|v| !v.is_finite()) {
+        bail!("Provided samples must be finite");
+    }
+    Ok(())
+}
+
+/// Smallest and largest value of a set of samples
+fn range(x: &Array1<f64>) -> (f64, f64) {
+    x.iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(*v), hi.max(*v))
+        })
+}
+
+/// Samples sorted in increasing order
+fn sorted(x: &Array1<f64>) -> Vec<f64> {
+    let mut sorted = x.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+/// Quantile of sorted samples with linear interpolation between the closest ranks
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lower, upper) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lower] + (pos - lower as f64) * (sorted[upper] - sorted[lower])
+}
+
+/// # Equal-Width Binning
+/// <https://en.wikipedia.org/wiki/Data_binning>
+///
+/// Maps continuous samples to the indices of `nbins` bins of equal width spanning their range,
+/// returning the bin indices along with the number of bins.
+///
+/// The maximum falls in the last bin, and if every sample is equal they all fall in the first.
+/// The output can be passed straight to [`hist1d()`](crate::hist1d()) or
+/// [`prob2d()`](crate::prob2d()).
+///
+/// # Errors
+/// If the samples are empty or contain non-finite values, or if the number of bins is zero.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{equal_width, hist1d, BinRule};
+///
+/// let x = array![0.0, 0.1, 0.5, 0.9, 1.0];
+/// let (bins, nbins) = equal_width(&x, 2).unwrap();
+/// assert_eq!(bins, array![0, 0, 1, 1, 1]);
+/// assert_eq!(hist1d(&bins, nbins).unwrap(), array![2, 3]);
+///
+/// // choosing the number of bins with a rule
+/// let (_, nbins) = equal_width(&x, BinRule::Sturges.nbins(&x).unwrap()).unwrap();
+/// assert_eq!(nbins, 4);
+/// ```
+pub fn equal_width(x: &Array1<f64>, nbins: usize) -> Result<(Array1<usize>, usize)> {
+    validate(x)?;
+    if nbins == 0 {
+        bail!("The number of bins must be at least one");
+    }
+    let (min, max) = range(x);
+    let width = (max - min) / nbins as f64;
+    let bins = x.mapv(|v| {
+        if width > 0.0 {
+            (((v - min) / width) as usize).min(nbins - 1)
+        } else {
+            0
+        }
+    });
+    Ok((bins, nbins))
+}
+
+/// # Equal-Frequency Binning
+/// <https://en.wikipedia.org/wiki/Quantile>
+///
+/// Maps continuous samples to the indices of at most `nbins` quantile bins holding roughly the
+/// same number of samples each, returning the bin indices along with the number of bins.
+///
+/// Equal samples always share a bin, so heavily tied samples can produce fewer bins than
+/// requested, but never an empty one. The output can be passed straight to
+/// [`hist1d()`](crate::hist1d()) or [`prob2d()`](crate::prob2d()).
+///
+/// # Errors
+/// If the samples are empty or contain non-finite values, or if the number of bins is zero.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{equal_frequency, hist1d};
+///
+/// let x = array![10.0, 0.1, 1000.0, 0.2, 5.0, 3.0];
+/// let (bins, nbins) = equal_frequency(&x, 3).unwrap();
+/// assert_eq!(bins, array![2, 0, 2, 0, 1, 1]);
+/// assert_eq!(hist1d(&bins, nbins).unwrap(), array![2, 2, 2]);
+///
+/// // ties share a bin
+/// let (bins, nbins) = equal_frequency(&array![1.0, 1.0, 1.0, 2.0], 4).unwrap();
+/// assert_eq!(bins, array![0, 0, 0, 1]);
+/// assert_eq!(nbins, 2);
+/// ```
+pub fn equal_frequency(x: &Array1<f64>, nbins: usize) -> Result<(Array1<usize>, usize)> {
+    validate(x)?;
+    if nbins == 0 {
+        bail!("The number of bins must be at least one");
+    }
+    let sorted = sorted(x);
+    let n = sorted.len();
+
+    // lower edge of every bin after the first, skipping duplicates and the minimum
+    let mut edges: Vec<f64> = (1..nbins).map(|b| sorted[b * n / nbins]).collect();
+    edges.dedup();
+    edges.retain(|e| *e > sorted[0]);
+
+    let bins = x.mapv(|v| edges.partition_point(|e| *e <= v));
+    Ok((bins, edges.len() + 1))
+}
+
+/// Counts of each class among a subset of samples
+fn class_counts(labels: &Array1<usize>, subset: &[usize], nclasses: usize) -> Array1<usize> {
+    let mut counts = Array1::zeros(nclasses);
+    for idx in subset {
+        counts[labels[*idx]] += 1;
+    }
+    counts
+}
+
+/// Entropy of the class counts along with the number of classes present
+fn class_entropy(counts: &Array1<usize>) -> (f64, f64) {
+    let total = counts.sum() as f64;
+    let present = counts.iter().filter(|c| **c > 0).count();
+    (entropy(&counts.mapv(|c| c as f64 / total)), present as f64)
+}
+
+/// `n ln[ n ]` of a count, which is zero for an empty count
+fn xlnx(n: usize) -> f64 {
+    if n == 0 {
+        0.0
+    } else {
+        let n = n as f64;
+        n * n.ln()
+    }
+}
+
+/// Recursively collects the accepted cut points of a subset of samples sorted by feature
+fn mdl_split(
+    x: &Array1<f64>,
+    labels: &Array1<usize>,
+    subset: &[usize],
+    nclasses: usize,
+    cuts: &mut Vec<f64>,
+) {
+    let n = subset.len();
+    let total = class_counts(labels, subset, nclasses);
+    let (h, k) = class_entropy(&total);
+    if h == 0.0 {
+        return;
+    }
+
+    // sweep the boundaries between distinct feature values for the most informative cut,
+    // updating Σ n(c) ln[ n(c) ] of both sides as each sample crosses over
+    let mut left = Array1::<usize>::zeros(nclasses);
+    let mut right = total.clone();
+    let (mut s_left, mut s_right) = (0.0, total.iter().map(|c| xlnx(*c)).sum::<f64>());
+    let mut best: Option<(f64, usize)> = None;
+    for pos in 1..n {
+        let c = labels[subset[pos - 1]];
+        s_left += xlnx(left[c] + 1) - xlnx(left[c]);
+        s_right += xlnx(right[c] - 1) - xlnx(right[c]);
+        left[c] += 1;
+        right[c] -= 1;
+        if x[subset[pos - 1]] == x[subset[pos]] {
+            continue;
+        }
+        // H(C|T) = [ n1 H(C1) + n2 H(C2) ] / N with n H(C) = n ln[ n ] - Σ n(c) ln[ n(c) ]
+        let h_cut = (xlnx(pos) - s_left + xlnx(n - pos) - s_right) / n as f64;
+        if best.is_none_or(|(h_best, _)| h_cut < h_best) {
+            best = Some((h_cut, pos));
+        }
+    }
+    let Some((h_cut, pos)) = best else {
+        return;
+    };
+
+    let (lower, upper) = subset.split_at(pos);
+    let (h1, k1) = class_entropy(&class_counts(labels, lower, nclasses));
+    let (h2, k2) = class_entropy(&class_counts(labels, upper, nclasses));
+    // ln[ 3^k - 2 ] without overflowing for many classes
+    let ln_partitions = k * 3_f64.ln() + (1.0 - 2.0 * 3_f64.powf(-k)).ln();
+    let delta = ln_partitions - (k * h - k1 * h1 - k2 * h2);
+    let n = n as f64;
+    if h - h_cut <= ((n - 1.0).ln() + delta) / n {
+        return;
+    }
+    cuts.push((x[lower[pos - 1]] + x[upper[0]]) / 2.0);
+    mdl_split(x, labels, lower, nclasses, cuts);
+    mdl_split(x, labels, upper, nclasses, cuts);
+}
+
+/// # MDL Discretization
+/// <https://en.wikipedia.org/wiki/Discretization_of_continuous_features>
+///
+/// Bins a continuous feature according to a class label with the Fayyad–Irani (1993) entropy
+/// based discretization, returning the bin indices along with the sorted cut points.
+///
+/// Samples are split recursively at the midpoint `T` between two distinct feature values that
+/// minimizes the conditional entropy `H(C|T)` of the class `C` given the side of the cut, which
+/// is the entropy of the classes on each side weighted by its `N1` and `N2` samples. A subset `S`
+/// of `N` samples with `k` classes is only split into `S1` and `S2` if the gain passes the
+/// minimum description length criterion:
+/// ```math
+/// H(C|T) = N1/N H(C1) + N2/N H(C2)
+/// H(C) - H(C|T) > (ln[ N - 1 ] + Δ) / N
+/// Δ = ln[ 3^k - 2 ] - (k H(C) - k1 H(C1) - k2 H(C2))
+/// ```
+///
+/// Samples above a cut point fall in the next bin, and the output can be passed straight to
+/// [`hist1d()`](crate::hist1d()) or [`prob2d()`](crate::prob2d()) with `cuts.len() + 1` bins.
+///
+/// # Errors
+/// If the feature is empty or contains non-finite values, or if the feature and the labels do
+/// not have the same length.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::mdl_discretize;
+///
+/// let x = array![1.0, 12.0, 2.0, 11.0, 3.0, 10.0, 25.0, 21.0, 22.0];
+/// let labels = array![0, 1, 0, 1, 0, 1, 2, 2, 2];
+/// let (bins, cuts) = mdl_discretize(&x, &labels).unwrap();
+/// assert_eq!(cuts, vec![6.5, 16.5]);
+/// assert_eq!(bins, array![0, 1, 0, 1, 0, 1, 2, 2, 2]);
+/// ```
+pub fn mdl_discretize(
+    x: &Array1<f64>,
+    labels: &Array1<usize>,
+) -> Result<(Array1<usize>, Vec<f64>)> {
+    validate(x)?;
+    if labels.len() != x.len() {
+        bail!("Provided feature and labels must be of equal size");
+    }
+    // remap the labels to dense class indices so the cost does not depend on their values
+    let mut classes: Vec<usize> = labels.to_vec();
+    classes.sort_unstable();
+    classes.dedup();
+    let nclasses = classes.len();
+    let labels = labels.mapv(|l| classes.binary_search(&l).unwrap_or_default());
+    let mut order: Vec<usize> = (0..x.len()).collect();
+    order.sort_by(|a, b| x[*a].total_cmp(&x[*b]));
+
+    let mut cuts = Vec::new();
+    mdl_split(x, &labels, &order, nclasses, &mut cuts);
+    cuts.sort_by(f64::total_cmp);
+    let bins = x.mapv(|v| cuts.partition_point(|c| *c < v));
+    Ok((bins, cuts))
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{equal_frequency, equal_width, mdl_discretize, BinRule};
+    use crate::{hist::hist1d, prob::prob2d};
+    use ndarray::{array, Array1};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    const N_ITER: usize = 100;
+    const ARRAY_SIZE: usize = 1000;
+    const RULES: [BinRule; 5] = [
+        BinRule::Sturges,
+        BinRule::Scott,
+        BinRule::FreedmanDiaconis,
+        BinRule::Doane,
+        BinRule::Sqrt,
+    ];
+
+    #[test]
+    fn test_equal_width() {
+        let x = array![-1.0, 0.0, 0.49, 0.5, 1.0];
+        let (bins, nbins) = equal_width(&x, 4).unwrap();
+        assert_eq!(bins, array![0, 2, 2, 3, 3]);
+        assert_eq!(nbins, 4);
+
+        let (bins, nbins) = equal_width(&array![2.0, 2.0], 3).unwrap();
+        assert_eq!(bins, array![0, 0]);
+        assert_eq!(nbins, 3);
+    }
+
+    #[test]
+    fn test_equal_frequency() {
+        for _ in 0..N_ITER {
+            let x = Array1::random(ARRAY_SIZE, Uniform::new(-5.0, 5.0));
+            let (bins, nbins) = equal_frequency(&x, 8).unwrap();
+            assert_eq!(nbins, 8);
+            assert!(hist1d(&bins, nbins).unwrap().iter().all(|c| *c == 125));
+        }
+    }
+
+    #[test]
+    fn test_equal_frequency_ties() {
+        for _ in 0..N_ITER {
+            let x = Array1::random(ARRAY_SIZE, Uniform::new(0, 5)).mapv(|v| v as f64);
+            let (bins, nbins) = equal_frequency(&x, 10).unwrap();
+            assert!(nbins <= 5);
+            assert!(hist1d(&bins, nbins).unwrap().iter().all(|c| *c > 0));
+
+            // the bin order follows the sample order and ties share a bin
+            let mut pairs: Vec<(f64, usize)> =
+                x.iter().copied().zip(bins.iter().copied()).collect();
+            pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+            for w in pairs.windows(2) {
+                assert!(w[0].1 <= w[1].1);
+                assert!(w[0].0 < w[1].0 || w[0].1 == w[1].1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        let x = Array1::range(0.0, 100.0, 1.0);
+        let expected = [8, 5, 5, 8, 10];
+        for (rule, nbins) in RULES.iter().zip(expected) {
+            assert_eq!(rule.nbins(&x).unwrap(), nbins);
+        }
+
+        // skewed samples need more bins under Doane's rule than Sturges'
+        let x = array![1.0, 1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0, 34.0, 55.0, 89.0, 144.0];
+        let skewed = [5, 3, 5, 7, 4];
+        for (rule, nbins) in RULES.iter().zip(skewed) {
+            assert_eq!(rule.nbins(&x).unwrap(), nbins);
+        }
+
+        // constant samples collapse the width-based rules to one bin
+        let x = Array1::from_elem(10, 3.0);
+        assert_eq!(BinRule::Scott.nbins(&x).unwrap(), 1);
+        assert_eq!(BinRule::FreedmanDiaconis.nbins(&x).unwrap(), 1);
+        assert_eq!(BinRule::Doane.nbins(&x).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_joint_binning() {
+        let x = Array1::random(ARRAY_SIZE, Uniform::new(0.0, 1.0));
+        let y = x.mapv(|v| v * v);
+        let (bx, nx) = equal_width(&x, BinRule::Scott.nbins(&x).unwrap()).unwrap();
+        let (by, ny) = equal_frequency(&y, BinRule::Sqrt.nbins(&y).unwrap()).unwrap();
+        let p_xy = prob2d(&bx, &by, nx, ny).unwrap();
+        assert_eq!(p_xy.shape(), &[nx, ny]);
+    }
+
+    #[test]
+    fn test_mdl_separable() {
+        // three classes on disjoint intervals are cut exactly at the gaps
+        for _ in 0..N_ITER {
+            let labels = Array1::random(ARRAY_SIZE, Uniform::new(0, 3));
+            let x = &labels.mapv(|l| 2.0 * l as f64)
+                + &Array1::random(ARRAY_SIZE, Uniform::new(0.0, 1.0));
+            let (bins, cuts) = mdl_discretize(&x, &labels).unwrap();
+            assert_eq!(cuts.len(), 2);
+            assert!(cuts[0] > 1.0 && cuts[0] < 2.0);
+            assert!(cuts[1] > 3.0 && cuts[1] < 4.0);
+            assert_eq!(bins, labels);
+        }
+    }
+
+    #[test]
+    fn test_mdl_independent() {
+        // a feature unrelated to the labels is not worth cutting
+        for _ in 0..N_ITER {
+            let labels = Array1::random(ARRAY_SIZE, Uniform::new(0, 2));
+            let x = Array1::random(ARRAY_SIZE, Uniform::new(0.0, 1.0));
+            let (bins, cuts) = mdl_discretize(&x, &labels).unwrap();
+            assert!(cuts.is_empty());
+            assert!(bins.iter().all(|b| *b == 0));
+        }
+    }
+
+    #[test]
+    fn test_mdl_sparse_labels() {
+        // only the grouping of the labels matters, not their values
+        for _ in 0..N_ITER {
+            let labels = Array1::random(ARRAY_SIZE, Uniform::new(0, 3));
+            let x =
+                &labels.mapv(|l| l as f64) + &Array1::random(ARRAY_SIZE, Uniform::new(0.0, 1.5));
+            let sparse = labels.mapv(|l| [7, 1_000_000, usize::MAX][l]);
+            assert_eq!(
+                mdl_discretize(&x, &labels).unwrap(),
+                mdl_discretize(&x, &sparse).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_mdl_pure() {
+        let x = array![3.0, 1.0, 2.0];
+        let (bins, cuts) = mdl_discretize(&x, &array![1, 1, 1]).unwrap();
+        assert!(cuts.is_empty());
+        assert_eq!(bins, array![0, 0, 0]);
+
+        // tied feature values are never separated
+        let (_, cuts) = mdl_discretize(&array![1.0, 1.0, 1.0, 1.0], &array![0, 1, 0, 1]).unwrap();
+        assert!(cuts.is_empty());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(mdl_discretize(&array![1.0, 2.0], &array![0]).is_err());
+        assert!(mdl_discretize(&array![], &array![]).is_err());
+        assert!(equal_width(&array![], 3).is_err());
+        assert!(equal_width(&array![1.0, 2.0], 0).is_err());
+        assert!(equal_frequency(&array![1.0, f64::NAN], 2).is_err());
+        assert!(BinRule::Sturges.nbins(&array![f64::INFINITY]).is_err());
+    }
+}
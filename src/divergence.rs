@@ -0,0 +1,180 @@
+use ndarray::{Array, Dimension, Zip};
+
+/// # Kullback–Leibler Divergence
+/// <https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence>
+///
+/// Calculates the relative entropy of a probability array `P` with respect to another probability
+/// array `Q` of the same shape measured in nats.
+///
+/// ```math
+/// D(P||Q) = Σ p(x) * ln[ p(x) / q(x) ]
+/// ```
+///
+/// Terms where `p(x) = 0` are skipped as in [`entropy()`](crate::entropy()). If `q(x) = 0` where
+/// `p(x) > 0` the divergence is infinite and `f64::INFINITY` is returned.
+///
+/// # Panics
+/// If the two arrays are not of equal shape.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::kl_divergence;
+///
+/// let p = array![0.5, 0.5];
+/// let q = array![0.25, 0.75];
+/// let d = kl_divergence(&p, &q);
+/// assert!((d - 0.143_841_036_225_890_1).abs() < 1e-12);
+///
+/// // Measures: D(P||P) = 0
+/// assert_eq!(kl_divergence(&p, &p), 0.0);
+///
+/// // Measures: the support of P is not covered by Q
+/// assert_eq!(kl_divergence(&p, &array![1.0, 0.0]), f64::INFINITY);
+/// ```
+#[must_use]
+pub fn kl_divergence<D: Dimension>(p: &Array<f64, D>, q: &Array<f64, D>) -> f64 {
+    assert_eq!(
+        p.shape(),
+        q.shape(),
+        "Provided distributions must be of equal shape"
+    );
+    Zip::from(p).and(q).fold(0.0, |acc, p, q| {
+        if *p == 0.0 {
+            acc
+        } else if *q == 0.0 {
+            f64::INFINITY
+        } else {
+            acc + (p * (p / q).ln())
+        }
+    })
+}
+
+/// # Cross Entropy
+/// <https://en.wikipedia.org/wiki/Cross_entropy>
+///
+/// Calculates the cross entropy of a probability array `Q` relative to another probability array
+/// `P` of the same shape measured in nats.
+///
+/// ```math
+/// H(P,Q) = -Σ p(x) * ln[ q(x) ]
+/// ```
+///
+/// Terms where `p(x) = 0` are skipped as in [`entropy()`](crate::entropy()). If `q(x) = 0` where
+/// `p(x) > 0` the cross entropy is infinite and `f64::INFINITY` is returned.
+///
+/// # Panics
+/// If the two arrays are not of equal shape.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{cross_entropy, entropy, kl_divergence};
+///
+/// let p = array![0.5, 0.25, 0.25];
+/// let q = array![0.25, 0.25, 0.5];
+///
+/// // Measures: H(P,Q) = H(P) + D(P||Q)
+/// let h_pq = cross_entropy(&p, &q);
+/// assert!((h_pq - (entropy(&p) + kl_divergence(&p, &q))).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn cross_entropy<D: Dimension>(p: &Array<f64, D>, q: &Array<f64, D>) -> f64 {
+    assert_eq!(
+        p.shape(),
+        q.shape(),
+        "Provided distributions must be of equal shape"
+    );
+    Zip::from(p).and(q).fold(0.0, |acc, p, q| {
+        if *p == 0.0 {
+            acc
+        } else if *q == 0.0 {
+            f64::INFINITY
+        } else {
+            acc - (p * q.ln())
+        }
+    })
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{cross_entropy, kl_divergence};
+    use crate::{entropy::entropy, joint_entropy};
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1, Array3};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    const N_ITER: usize = 1000;
+    const ARRAY_SIZE: usize = 100;
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn test_zero_skipping() {
+        let p = array![0.0, 0.5, 0.5];
+        let q = array![0.0, 0.5, 0.5];
+        assert_eq!(kl_divergence(&p, &q), 0.0);
+        assert_relative_eq!(cross_entropy(&p, &q), entropy(&p));
+    }
+
+    #[test]
+    fn test_infinite() {
+        let p = array![[0.5, 0.0], [0.0, 0.5]];
+        let q = array![[1.0, 0.0], [0.0, 0.0]];
+        assert_eq!(kl_divergence(&p, &q), f64::INFINITY);
+        assert_eq!(cross_entropy(&p, &q), f64::INFINITY);
+
+        // zeros in Q outside the support of P are fine
+        assert!(kl_divergence(&q, &p).is_finite());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unequal_shape() {
+        let p = array![0.5, 0.5];
+        let q = array![0.25, 0.25, 0.5];
+        let _ = kl_divergence(&p, &q);
+    }
+
+    #[test]
+    /// https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence#Properties
+    fn test_nonnegative() {
+        for _ in 0..N_ITER {
+            let c_p = Array1::random(ARRAY_SIZE, Uniform::new(0.1, 0.8));
+            let c_q = Array1::random(ARRAY_SIZE, Uniform::new(0.1, 0.8));
+            let p = &c_p / c_p.sum();
+            let q = &c_q / c_q.sum();
+            assert!(kl_divergence(&p, &q) >= 0.0);
+        }
+    }
+
+    #[test]
+    /// https://en.wikipedia.org/wiki/Cross_entropy#Motivation
+    fn test_cross_entropy_identity() {
+        for _ in 0..N_ITER {
+            let c_p = Array1::random(ARRAY_SIZE, Uniform::new(0.1, 0.8));
+            let c_q = Array1::random(ARRAY_SIZE, Uniform::new(0.1, 0.8));
+            let p = &c_p / c_p.sum();
+            let q = &c_q / c_q.sum();
+
+            // Measures: H(P,Q) = H(P) + D(P||Q)
+            assert_relative_eq!(
+                cross_entropy(&p, &q),
+                entropy(&p) + kl_divergence(&p, &q),
+                epsilon = EPSILON
+            );
+
+            let c_p = Array3::random((2, 3, ARRAY_SIZE), Uniform::new(0.1, 0.8));
+            let c_q = Array3::random((2, 3, ARRAY_SIZE), Uniform::new(0.1, 0.8));
+            let p = &c_p / c_p.sum();
+            let q = &c_q / c_q.sum();
+
+            // Measures: H(P,Q) = H(P) + D(P||Q)
+            assert_relative_eq!(
+                cross_entropy(&p, &q),
+                joint_entropy!(&p) + kl_divergence(&p, &q),
+                epsilon = EPSILON
+            );
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::unit::Unit;
 use ndarray::Array1;
 
 /// # Entropy
@@ -24,15 +25,44 @@ use ndarray::Array1;
 /// assert_eq!(h_x, 0.6931471805599453)
 /// ```
 ///
+/// Also accepts a [`SparseHist`](crate::SparseHist) of counts, in which case the entropy of its
+/// (joint) distribution is computed from the observed combinations alone.
 #[must_use]
-pub fn entropy(px: &Array1<f64>) -> f64 {
-    (0..px.len()).fold(0.0, |acc, idx| {
-        if px[idx] == 0.0 {
-            acc
-        } else {
-            acc - (px[idx] * px[idx].ln())
-        }
-    })
+pub fn entropy<P: Entropy + ?Sized>(px: &P) -> f64 {
+    px.entropy()
+}
+
+/// Calculates the [`entropy()`] measured in the provided [`Unit`].
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{entropy_in, Unit};
+///
+/// let p_x = array![0.25, 0.25, 0.25, 0.25];
+/// assert_eq!(entropy_in(&p_x, Unit::Bits), 2.0);
+/// ```
+#[must_use]
+pub fn entropy_in<P: Entropy + ?Sized>(px: &P, unit: Unit) -> f64 {
+    unit.convert(px.entropy())
+}
+
+/// Distributions accepted by [`entropy()`]
+pub trait Entropy {
+    /// Calculates the entropy of the distribution measured in nats.
+    fn entropy(&self) -> f64;
+}
+
+impl Entropy for Array1<f64> {
+    fn entropy(&self) -> f64 {
+        (0..self.len()).fold(0.0, |acc, idx| {
+            if self[idx] == 0.0 {
+                acc
+            } else {
+                acc - (self[idx] * self[idx].ln())
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -40,11 +70,12 @@ mod testing {
 
     use super::entropy;
     use ndarray::array;
+    use std::f64::consts::LN_2;
 
     #[test]
     fn test_entropy() {
         // WolframAlpha: Entropy[{0, 0, 1, 1}]
-        assert_eq!(entropy(&array![0.5, 0.5]), 0.6931471805599453);
+        assert_eq!(entropy(&array![0.5, 0.5]), LN_2);
 
         // WolframAlpha: Entropy[{0, 0, 1, 1, 2, 2}]
         assert_eq!(
@@ -55,7 +86,7 @@ mod testing {
         // WolframAlpha: Entropy[{0, 0, 1, 1, 2, 2, 3, 3}]
         assert_eq!(
             entropy(&array![0.25, 0.25, 0.25, 0.25]),
-            1.38629436111989061
+            1.386_294_361_119_890_6
         );
     }
 }
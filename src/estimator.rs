@@ -0,0 +1,333 @@
+use crate::special::digamma;
+use ndarray::{Array, Array2, Array3, Axis, Dimension};
+
+/// # Entropy Estimators
+/// Estimators of the entropy of a distribution from the observed event counts.
+///
+/// The plug-in estimate used by [`entropy()`](crate::entropy()) is biased downward when the number
+/// of samples is small compared to the number of bins. The other estimators correct for that bias.
+///
+/// * [`PlugIn`](EntropyEstimator::PlugIn): maximum likelihood estimate `-Σ n/N * ln[ n/N ]`.
+/// * [`MillerMadow`](EntropyEstimator::MillerMadow): plug-in plus `(m - 1) / 2N` where `m` is the
+///   number of non-empty bins.
+/// * [`Jackknife`](EntropyEstimator::Jackknife): leave-one-out jackknife bias correction of the
+///   plug-in estimate.
+/// * [`Grassberger`](EntropyEstimator::Grassberger): the Grassberger (2003) estimator
+///   `ln[ N ] - 1/N Σ n G(n)` where `G(n) = ψ(n) + 1/2 (-1)^n [ ψ((n + 1) / 2) - ψ(n / 2) ]`.
+/// * [`ChaoShen`](EntropyEstimator::ChaoShen): coverage-adjusted Horvitz–Thompson estimator of
+///   Chao and Shen (2003).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntropyEstimator {
+    #[default]
+    PlugIn,
+    MillerMadow,
+    Jackknife,
+    Grassberger,
+    ChaoShen,
+}
+
+impl EntropyEstimator {
+    /// Estimates the entropy in nats from the non-empty bin counts
+    fn estimate(self, counts: &[f64]) -> f64 {
+        let total: f64 = counts.iter().sum();
+        if total == 0.0 {
+            return 0.0;
+        }
+        match self {
+            Self::PlugIn => plugin(counts, total),
+            Self::MillerMadow => {
+                plugin(counts, total) + (counts.len() as f64 - 1.0) / (2.0 * total)
+            }
+            Self::Jackknife => jackknife(counts, total),
+            Self::Grassberger => grassberger(counts, total),
+            Self::ChaoShen => chao_shen(counts, total),
+        }
+    }
+}
+
+/// `x ln[ x ]` with the convention that `0 ln[ 0 ] = 0`
+fn xlnx(x: f64) -> f64 {
+    if x == 0.0 {
+        0.0
+    } else {
+        x * x.ln()
+    }
+}
+
+fn plugin(counts: &[f64], total: f64) -> f64 {
+    total.ln() - counts.iter().map(|n| xlnx(*n)).sum::<f64>() / total
+}
+
+fn jackknife(counts: &[f64], total: f64) -> f64 {
+    let h = plugin(counts, total);
+    if total < 2.0 {
+        return h;
+    }
+    // removing a single event from a bin only changes that bin's term of the plug-in sum
+    let sum_nlogn: f64 = counts.iter().map(|n| xlnx(*n)).sum();
+    let sum_loo = counts.iter().fold(0.0, |acc, n| {
+        let sum = sum_nlogn - xlnx(*n) + xlnx(n - 1.0);
+        acc + n * ((total - 1.0).ln() - sum / (total - 1.0))
+    });
+    total * h - (total - 1.0) / total * sum_loo
+}
+
+fn grassberger(counts: &[f64], total: f64) -> f64 {
+    let sum_ng = counts.iter().fold(0.0, |acc, n| {
+        let sign = if (*n as usize).is_multiple_of(2) {
+            1.0
+        } else {
+            -1.0
+        };
+        let g = digamma(*n) + 0.5 * sign * (digamma((n + 1.0) / 2.0) - digamma(n / 2.0));
+        acc + n * g
+    });
+    total.ln() - sum_ng / total
+}
+
+fn chao_shen(counts: &[f64], total: f64) -> f64 {
+    let singletons = counts.iter().filter(|n| **n == 1.0).count() as f64;
+    // avoid a coverage of zero when every observation is a singleton
+    let singletons = if singletons == total {
+        total - 1.0
+    } else {
+        singletons
+    };
+    let coverage = 1.0 - singletons / total;
+    counts.iter().fold(0.0, |acc, n| {
+        let pa = coverage * n / total;
+        acc - (pa * pa.ln()) / (1.0 - (1.0 - pa).powf(total))
+    })
+}
+
+fn nonzero<D: Dimension>(counts: &Array<usize, D>) -> Vec<f64> {
+    counts
+        .iter()
+        .filter(|c| **c > 0)
+        .map(|c| *c as f64)
+        .collect()
+}
+
+/// # Estimated Entropy
+/// Estimates the entropy in nats of a histogram of event counts of any dimension using the
+/// selected [`EntropyEstimator`].
+///
+/// Multi-dimensional histograms (such as those built by [`hist2d`](crate::hist2d) or
+/// [`histnd`](crate::histnd)) give the estimated joint entropy.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{entropy, estimate_entropy, hist1d, prob1d, EntropyEstimator};
+///
+/// let x = array![0, 1, 1, 1, 2, 2];
+/// let c_x = hist1d(&x, 3).unwrap();
+///
+/// let h_plugin = estimate_entropy(&c_x, EntropyEstimator::PlugIn);
+/// let h_mm = estimate_entropy(&c_x, EntropyEstimator::MillerMadow);
+///
+/// assert!((h_plugin - entropy(&prob1d(&x, 3).unwrap())).abs() < 1e-12);
+/// assert!(h_mm > h_plugin);
+/// ```
+#[must_use]
+pub fn estimate_entropy<D: Dimension>(
+    counts: &Array<usize, D>,
+    estimator: EntropyEstimator,
+) -> f64 {
+    estimator.estimate(&nonzero(counts))
+}
+
+/// # Estimated Conditional Entropy
+/// Estimates the conditional entropy `H(X|Y)` in nats from a two-dimensional histogram of event
+/// counts using the selected [`EntropyEstimator`].
+///
+/// The estimate is calculated from the estimated joint and marginal entropies:
+/// ```math
+/// H(X|Y) = H(X,Y) - H(Y)
+/// ```
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{estimate_conditional_entropy, hist2d, EntropyEstimator};
+///
+/// let x = array![0, 1, 1, 1, 2, 2];
+/// let y = array![1, 0, 0, 1, 1, 1];
+/// let c_xy = hist2d(&x, &y, 3, 2).unwrap();
+/// let h = estimate_conditional_entropy(&c_xy, EntropyEstimator::ChaoShen);
+/// assert!(h >= 0.0);
+/// ```
+#[must_use]
+pub fn estimate_conditional_entropy(counts_xy: &Array2<usize>, estimator: EntropyEstimator) -> f64 {
+    let h_xy = estimate_entropy(counts_xy, estimator);
+    let h_y = estimate_entropy(&counts_xy.sum_axis(Axis(0)), estimator);
+    h_xy - h_y
+}
+
+/// # Estimated Mutual Information
+/// Estimates the mutual information `I(X;Y)` in nats from a two-dimensional histogram of event
+/// counts using the selected [`EntropyEstimator`].
+///
+/// The estimate is calculated from the estimated joint and marginal entropies:
+/// ```math
+/// I(X;Y) = H(X) + H(Y) - H(X,Y)
+/// ```
+///
+/// # Usage
+/// ```
+/// use ndarray::Array1;
+/// use ndarray_rand::{RandomExt, rand_distr::Uniform};
+/// use information::{estimate_mutual_information, hist2d, EntropyEstimator};
+///
+/// let x = Array1::random(50, Uniform::new(0, 4));
+/// let y = Array1::random(50, Uniform::new(0, 4));
+/// let c_xy = hist2d(&x, &y, 4, 4).unwrap();
+///
+/// let i_plugin = estimate_mutual_information(&c_xy, EntropyEstimator::PlugIn);
+/// let i_mm = estimate_mutual_information(&c_xy, EntropyEstimator::MillerMadow);
+///
+/// // Miller-Madow removes part of the upward bias of the plug-in mutual information
+/// assert!(i_mm <= i_plugin);
+/// ```
+#[must_use]
+pub fn estimate_mutual_information(counts_xy: &Array2<usize>, estimator: EntropyEstimator) -> f64 {
+    let h_xy = estimate_entropy(counts_xy, estimator);
+    let h_x = estimate_entropy(&counts_xy.sum_axis(Axis(1)), estimator);
+    let h_y = estimate_entropy(&counts_xy.sum_axis(Axis(0)), estimator);
+    h_x + h_y - h_xy
+}
+
+/// # Estimated Conditional Mutual Information
+/// Estimates the conditional mutual information `I(X;Y|Z)` in nats from a three-dimensional
+/// histogram of event counts using the selected [`EntropyEstimator`].
+///
+/// The estimate is calculated from the estimated joint entropies:
+/// ```math
+/// I(X;Y|Z) = H(X,Z) + H(Y,Z) - H(X,Y,Z) - H(Z)
+/// ```
+///
+/// # Usage
+/// ```
+/// use ndarray::Array1;
+/// use ndarray_rand::{RandomExt, rand_distr::Uniform};
+/// use information::{estimate_conditional_mutual_information, hist3d, EntropyEstimator};
+///
+/// let x = Array1::random(1000, Uniform::new(0, 2));
+/// let y = Array1::random(1000, Uniform::new(0, 2));
+/// let z = Array1::random(1000, Uniform::new(0, 2));
+/// let c_xyz = hist3d(&x, &y, &z, 2, 2, 2).unwrap();
+///
+/// let cmi = estimate_conditional_mutual_information(&c_xyz, EntropyEstimator::Grassberger);
+/// assert!(cmi.abs() < 0.05);
+/// ```
+#[must_use]
+pub fn estimate_conditional_mutual_information(
+    counts_xyz: &Array3<usize>,
+    estimator: EntropyEstimator,
+) -> f64 {
+    let counts_xz = counts_xyz.sum_axis(Axis(1));
+    let counts_yz = counts_xyz.sum_axis(Axis(0));
+    let counts_z = counts_xz.sum_axis(Axis(0));
+    estimate_entropy(&counts_xz, estimator) + estimate_entropy(&counts_yz, estimator)
+        - estimate_entropy(counts_xyz, estimator)
+        - estimate_entropy(&counts_z, estimator)
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{
+        estimate_conditional_mutual_information, estimate_entropy, estimate_mutual_information,
+        EntropyEstimator,
+    };
+    use crate::{
+        cmi::conditional_mutual_information,
+        entropy::entropy,
+        hist::{hist1d, hist2d, hist3d},
+        mutual::mutual_information,
+        prob::{prob1d, prob2d, prob3d},
+    };
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    const N_ITER: usize = 500;
+    const EPSILON: f64 = 1e-12;
+
+    const ESTIMATORS: [EntropyEstimator; 5] = [
+        EntropyEstimator::PlugIn,
+        EntropyEstimator::MillerMadow,
+        EntropyEstimator::Jackknife,
+        EntropyEstimator::Grassberger,
+        EntropyEstimator::ChaoShen,
+    ];
+
+    #[test]
+    fn test_reference_values() {
+        // reference values calculated with mpmath
+        let counts = array![1, 3, 2, 0, 5];
+        let expected = [
+            1.240_684_291_953_395_9,
+            1.377_047_928_317_032_2,
+            1.440_880_995_810_001_5,
+            1.547_045_997_047_727_5,
+            1.408_755_054_942_351,
+        ];
+        for (estimator, h) in ESTIMATORS.iter().zip(expected) {
+            assert_relative_eq!(estimate_entropy(&counts, *estimator), h, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let counts = array![0, 0, 0];
+        for estimator in ESTIMATORS {
+            assert_eq!(estimate_entropy(&counts, estimator), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_plugin_agreement() {
+        let x = Array1::random(100, Uniform::new(0, 4));
+        let y = Array1::random(100, Uniform::new(0, 4));
+        let z = Array1::random(100, Uniform::new(0, 4));
+        let estimator = EntropyEstimator::PlugIn;
+
+        assert_relative_eq!(
+            estimate_entropy(&hist1d(&x, 4).unwrap(), estimator),
+            entropy(&prob1d(&x, 4).unwrap()),
+            epsilon = EPSILON
+        );
+        assert_relative_eq!(
+            estimate_mutual_information(&hist2d(&x, &y, 4, 4).unwrap(), estimator),
+            mutual_information(&prob2d(&x, &y, 4, 4).unwrap()),
+            epsilon = EPSILON
+        );
+        assert_relative_eq!(
+            estimate_conditional_mutual_information(
+                &hist3d(&x, &y, &z, 4, 4, 4).unwrap(),
+                estimator
+            ),
+            conditional_mutual_information(&prob3d(&x, &y, &z, 4, 4, 4).unwrap()),
+            epsilon = EPSILON
+        );
+    }
+
+    #[test]
+    /// Every correction should be closer to the true entropy than the plug-in on average
+    fn test_bias_reduction() {
+        let nbins = 20;
+        let truth = (nbins as f64).ln();
+        let mut bias = [0.0; 5];
+        for _ in 0..N_ITER {
+            let x = Array1::random(30, Uniform::new(0, nbins));
+            let counts = hist1d(&x, nbins).unwrap();
+            for (idx, estimator) in ESTIMATORS.iter().enumerate() {
+                bias[idx] += (estimate_entropy(&counts, *estimator) - truth) / N_ITER as f64;
+            }
+        }
+        for b in &bias[1..] {
+            assert!(b.abs() < bias[0].abs());
+        }
+    }
+}
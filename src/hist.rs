@@ -1,5 +1,5 @@
 use anyhow::{bail, Result};
-use ndarray::{Array1, Array2, Array3};
+use ndarray::{Array1, Array2, Array3, ArrayD, IxDyn};
 
 /// Calculates the number of events of each integer bin for a one-dimensional integer array.
 ///
@@ -117,9 +117,56 @@ pub fn hist3d(
     Ok(events)
 }
 
+/// Calculates the event intersection between an arbitrary number of integer arrays of equal size
+///
+/// Each variable is paired with its own number of bins, and the resulting histogram has one axis
+/// per variable in the order provided.
+///
+/// # Usage
+///
+/// ```
+/// use ndarray::array;
+/// use information::{hist3d, histnd};
+///
+/// let arr_a = array![0, 1, 1];
+/// let arr_b = array![0, 0, 1];
+/// let arr_c = array![1, 1, 1];
+/// let hist = histnd(&[arr_a.clone(), arr_b.clone(), arr_c.clone()], &[2, 2, 2]).unwrap();
+/// let expected = hist3d(&arr_a, &arr_b, &arr_c, 2, 2, 2).unwrap();
+/// assert_eq!(hist.shape(), &[2, 2, 2]);
+/// assert_eq!(hist, expected.into_dyn());
+/// ```
+pub fn histnd(arrs: &[Array1<usize>], nbins: &[usize]) -> Result<ArrayD<usize>> {
+    if arrs.is_empty() {
+        bail!("At least one array must be provided");
+    }
+    if arrs.len() != nbins.len() {
+        bail!("A number of bins must be provided for each array");
+    }
+    let size = arrs[0].len();
+    if arrs.iter().any(|arr| arr.len() != size) {
+        bail!("Provided arrays must be of equal size");
+    }
+    let mut events = ArrayD::zeros(IxDyn(nbins));
+    let mut index = vec![0; arrs.len()];
+    for idx in 0..size {
+        for (dim, arr) in arrs.iter().enumerate() {
+            if arr[idx] >= nbins[dim] {
+                bail!(
+                    "Out of index error found - raise the number of bins provided to array {}",
+                    dim + 1
+                );
+            }
+            index[dim] = arr[idx];
+        }
+        events[index.as_slice()] += 1;
+    }
+    Ok(events)
+}
+
 #[cfg(test)]
 mod testing {
-    use super::{hist1d, hist2d, hist3d};
+    use super::{hist1d, hist2d, hist3d, histnd};
     use ndarray::array;
 
     #[test]
@@ -262,4 +309,52 @@ mod testing {
         let arr_c = array![1, 1, 1];
         hist3d(&arr_a, &arr_b, &arr_c, 2, 2, 1).unwrap();
     }
+
+    #[test]
+    fn test_nd_basic() {
+        let arr_a = array![0, 1, 1, 1, 2, 2];
+        let arr_b = array![1, 0, 0, 1, 2, 3];
+        let expected = hist2d(&arr_a, &arr_b, 3, 4).unwrap();
+        let hist = histnd(&[arr_a, arr_b], &[3, 4]).unwrap();
+        assert_eq!(hist.shape(), &[3, 4]);
+        assert_eq!(hist, expected.into_dyn());
+    }
+
+    #[test]
+    fn test_nd_4d() {
+        let arr_a = array![0, 1, 1, 0];
+        let arr_b = array![0, 0, 1, 0];
+        let arr_c = array![1, 1, 1, 1];
+        let arr_d = array![0, 2, 1, 0];
+        let hist = histnd(&[arr_a, arr_b, arr_c, arr_d], &[2, 2, 2, 3]).unwrap();
+        assert_eq!(hist.shape(), &[2, 2, 2, 3]);
+        assert_eq!(hist.sum(), 4);
+        assert_eq!(hist[[0, 0, 1, 0]], 2);
+        assert_eq!(hist[[1, 0, 1, 2]], 1);
+        assert_eq!(hist[[1, 1, 1, 1]], 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nd_unequal() {
+        let arr_a = array![0, 1, 1];
+        let arr_b = array![0, 0];
+        histnd(&[arr_a, arr_b], &[2, 2]).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nd_malform() {
+        let arr_a = array![0, 1, 1];
+        let arr_b = array![0, 0, 2];
+        histnd(&[arr_a, arr_b], &[2, 2]).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nd_missing_bins() {
+        let arr_a = array![0, 1, 1];
+        let arr_b = array![0, 0, 1];
+        histnd(&[arr_a, arr_b], &[2]).unwrap();
+    }
 }
@@ -0,0 +1,198 @@
+use crate::{
+    cmi::conditional_mutual_information, joint_entropy, multivariate::marginalize,
+    mutual::mutual_information,
+};
+use ndarray::{Array, Array3, Axis, Dimension};
+
+/// # Interaction Information
+/// <https://en.wikipedia.org/wiki/Interaction_information>
+///
+/// Calculates the interaction information of three random variables from their joint probability
+/// cube measured in nats, where the axes are `X`, `Y` and `Z` as in
+/// [`conditional_mutual_information()`](crate::conditional_mutual_information()).
+///
+/// This is how much knowing `Z` changes the information `X` and `Y` share, following the sign
+/// convention of McGill (1954). It is positive when `Z` creates dependence between `X` and `Y`
+/// (synergy) and negative when `Z` explains part of it away (redundancy):
+/// ```math
+/// I(X;Y;Z) = I(X;Y|Z) - I(X;Y)
+/// ```
+///
+/// The value is symmetric in the three variables and is the negative of the three-variable
+/// [`co_information()`].
+///
+/// # Usage
+/// ```
+/// use ndarray::Array3;
+/// use information::interaction_information;
+///
+/// // Z is the exclusive or of two fair coins X and Y
+/// let mut p_xyz = Array3::zeros((2, 2, 2));
+/// for x in 0..2 {
+///     for y in 0..2 {
+///         p_xyz[[x, y, x ^ y]] = 0.25;
+///     }
+/// }
+///
+/// let ii = interaction_information(&p_xyz);
+/// assert!((ii - 2.0_f64.ln()).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn interaction_information(p_xyz: &Array3<f64>) -> f64 {
+    let p_xy = p_xyz.sum_axis(Axis(2));
+    conditional_mutual_information(p_xyz) - mutual_information(&p_xy)
+}
+
+/// # Co-Information
+/// <https://en.wikipedia.org/wiki/Interaction_information>
+///
+/// Calculates the co-information (Bell 2003) of an `N`-dimensional joint probability array
+/// measured in nats, where each axis is one variable.
+///
+/// This generalizes [`mutual_information()`](crate::mutual_information()) to any number of
+/// variables by inclusion–exclusion over the joint entropies of every non-empty subset `T` of
+/// the variables `V`, each derived from the joint array:
+/// ```math
+/// I(X1;...;Xn) = -Σ_{T ⊆ V} (-1)^|T| H(T)
+/// ```
+///
+/// For two variables this is their mutual information. For three it is `I(X;Y) - I(X;Y|Z)`,
+/// which is negative when the variables are synergistic and positive when they are redundant,
+/// the opposite sign of [`interaction_information()`]. A single variable gives its entropy.
+///
+/// # Usage
+/// ```
+/// use ndarray::Array3;
+/// use information::co_information;
+///
+/// // three copies of a fair coin
+/// let mut p_xyz = Array3::zeros((2, 2, 2));
+/// p_xyz[[0, 0, 0]] = 0.5;
+/// p_xyz[[1, 1, 1]] = 0.5;
+///
+/// let ci = co_information(&p_xyz);
+/// assert!((ci - 2.0_f64.ln()).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn co_information<D: Dimension>(p: &Array<f64, D>) -> f64 {
+    let n = p.ndim();
+    (1..1usize << n)
+        .map(|mask| {
+            let keep: Vec<usize> = (0..n).filter(|axis| mask & (1 << axis) != 0).collect();
+            let h = joint_entropy!(marginalize(p, &keep));
+            if keep.len() % 2 == 1 {
+                h
+            } else {
+                -h
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{co_information, interaction_information};
+    use crate::{
+        cmi::conditional_mutual_information, entropy::entropy, mutual::mutual_information,
+    };
+    use approx::assert_relative_eq;
+    use ndarray::{Array1, Array2, Array3, Array4, Axis};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+    use std::f64::consts::LN_2;
+
+    const N_ITER: usize = 100;
+    const EPSILON: f64 = 1e-12;
+
+    fn xor() -> Array3<f64> {
+        let mut p_xyz = Array3::zeros((2, 2, 2));
+        for x in 0..2 {
+            for y in 0..2 {
+                p_xyz[[x, y, x ^ y]] = 0.25;
+            }
+        }
+        p_xyz
+    }
+
+    fn copy() -> Array3<f64> {
+        let mut p_xyz = Array3::zeros((2, 2, 2));
+        p_xyz[[0, 0, 0]] = 0.5;
+        p_xyz[[1, 1, 1]] = 0.5;
+        p_xyz
+    }
+
+    #[test]
+    fn test_sign_convention() {
+        // synergy is positive interaction information and negative co-information
+        assert_relative_eq!(interaction_information(&xor()), LN_2, epsilon = EPSILON);
+        assert_relative_eq!(co_information(&xor()), -LN_2, epsilon = EPSILON);
+
+        // redundancy is negative interaction information and positive co-information
+        assert_relative_eq!(interaction_information(&copy()), -LN_2, epsilon = EPSILON);
+        assert_relative_eq!(co_information(&copy()), LN_2, epsilon = EPSILON);
+    }
+
+    #[test]
+    /// Measures: I(X;Y;Z) = -I(X;Y;Z)_co and symmetric in the variables
+    fn test_three_variables() {
+        for _ in 0..N_ITER {
+            let c_xyz = Array3::random((2, 3, 4), Uniform::new(0.0, 1.0));
+            let p_xyz = &c_xyz / c_xyz.sum();
+            let ii = interaction_information(&p_xyz);
+            assert_relative_eq!(ii, -co_information(&p_xyz), epsilon = EPSILON);
+
+            let expected = conditional_mutual_information(&p_xyz)
+                - mutual_information(&p_xyz.sum_axis(Axis(2)));
+            assert_relative_eq!(ii, expected, epsilon = EPSILON);
+
+            let p_zxy = p_xyz.view().permuted_axes([2, 0, 1]).to_owned();
+            let p_yzx = p_xyz.view().permuted_axes([1, 2, 0]).to_owned();
+            assert_relative_eq!(ii, interaction_information(&p_zxy), epsilon = EPSILON);
+            assert_relative_eq!(ii, interaction_information(&p_yzx), epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_low_order() {
+        for _ in 0..N_ITER {
+            let c_x = Array1::random(5, Uniform::new(0.0, 1.0));
+            let p_x = &c_x / c_x.sum();
+            assert_relative_eq!(co_information(&p_x), entropy(&p_x), epsilon = EPSILON);
+
+            let c_xy = Array2::random((4, 5), Uniform::new(0.0, 1.0));
+            let p_xy = &c_xy / c_xy.sum();
+            assert_relative_eq!(
+                co_information(&p_xy),
+                mutual_information(&p_xy),
+                epsilon = EPSILON
+            );
+        }
+    }
+
+    #[test]
+    /// Measures: I(X1;...;Xn) = I(X1;...;Xn-1) - I(X1;...;Xn-1|Xn)
+    fn test_recursion() {
+        for _ in 0..N_ITER {
+            let c = Array4::random((2, 3, 2, 3), Uniform::new(0.0, 1.0));
+            let p = &c / c.sum();
+            let p_w = p.sum_axis(Axis(0)).sum_axis(Axis(0)).sum_axis(Axis(0));
+            let conditional = p_w
+                .iter()
+                .enumerate()
+                .map(|(w, pw)| {
+                    let slice = p.index_axis(Axis(3), w).to_owned() / *pw;
+                    pw * co_information(&slice)
+                })
+                .sum::<f64>();
+            let expected = co_information(&p.sum_axis(Axis(3))) - conditional;
+            assert_relative_eq!(co_information(&p), expected, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_independent() {
+        // four independent coins share nothing
+        let p = Array4::from_elem((2, 2, 2, 2), 1.0 / 16.0);
+        assert_relative_eq!(co_information(&p), 0.0, epsilon = EPSILON);
+    }
+}
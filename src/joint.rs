@@ -39,6 +39,15 @@
 /// assert!(h >= 0.0);
 /// ```
 ///
+/// An optional [`Unit`](crate::Unit) can be provided as a second argument:
+/// ```
+/// use ndarray::array;
+/// use information::{joint_entropy, Unit};
+///
+/// let p_xy = array![[0.25, 0.25], [0.25, 0.25]];
+/// assert_eq!(joint_entropy!(&p_xy, Unit::Bits), 2.0);
+/// ```
+///
 #[macro_export]
 macro_rules! joint_entropy {
     ($prob:expr) => {
@@ -50,6 +59,9 @@ macro_rules! joint_entropy {
             }
         })
     };
+    ($prob:expr, $unit:expr) => {
+        $crate::Unit::convert(&$unit, $crate::joint_entropy!($prob))
+    };
 }
 
 #[cfg(test)]
@@ -57,7 +69,6 @@ mod testing {
 
     use crate::{
         entropy::entropy,
-        joint_entropy,
         prob::{prob1d, prob2d},
     };
     use approx::assert_relative_eq;
@@ -0,0 +1,310 @@
+//! Nearest neighbour searches shared by the continuous estimators
+
+use anyhow::{bail, Result};
+use ndarray::{Array2, ArrayView1};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+/// Maximum number of points held by a leaf
+const LEAF_SIZE: usize = 16;
+
+/// # Metric
+/// Distance used for nearest neighbour searches between samples.
+///
+/// * [`Chebyshev`](Metric::Chebyshev): maximum norm `max |xi - yi|`.
+/// * [`Euclidean`](Metric::Euclidean): `sqrt[ Σ (xi - yi)² ]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Metric {
+    #[default]
+    Chebyshev,
+    Euclidean,
+}
+
+impl Metric {
+    /// Combines the per-dimension absolute differences into a distance
+    fn distance<I: Iterator<Item = f64>>(self, diffs: I) -> f64 {
+        match self {
+            Self::Chebyshev => diffs.fold(0.0, f64::max),
+            Self::Euclidean => diffs.map(|d| d * d).sum::<f64>().sqrt(),
+        }
+    }
+}
+
+/// Validates a set of sample matrices with an equal number of rows and a neighbour count
+pub(crate) fn validate_samples(samples: &[&Array2<f64>], k: usize) -> Result<usize> {
+    let n = samples[0].nrows();
+    if samples.iter().any(|s| s.nrows() != n) {
+        bail!("Provided sample matrices must have an equal number of rows");
+    }
+    if samples.iter().any(|s| s.ncols() == 0) {
+        bail!("Provided sample matrices must have at least one column");
+    }
+    if k == 0 {
+        bail!("The number of neighbours must be at least one");
+    }
+    if k >= n {
+        bail!("The number of neighbours must be smaller than the number of samples");
+    }
+    Ok(n)
+}
+
+struct Node {
+    /// range of the permuted point indices held by the node
+    start: usize,
+    end: usize,
+    /// bounding box of the points held by the node
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    /// child node indices, `None` for leaves
+    children: Option<(usize, usize)>,
+}
+
+/// Neighbour candidate ordered by its distance
+#[derive(PartialEq)]
+struct Candidate(f64, usize);
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// KD-tree over the rows of a sample matrix
+pub(crate) struct KdTree {
+    points: Vec<f64>,
+    dim: usize,
+    metric: Metric,
+    indices: Vec<usize>,
+    nodes: Vec<Node>,
+}
+
+impl KdTree {
+    pub(crate) fn new(samples: &Array2<f64>, metric: Metric) -> Self {
+        let dim = samples.ncols();
+        let mut tree = Self {
+            points: samples.iter().copied().collect(),
+            dim,
+            metric,
+            indices: (0..samples.nrows()).collect(),
+            nodes: Vec::new(),
+        };
+        if samples.nrows() > 0 {
+            tree.build(0, samples.nrows());
+        }
+        tree
+    }
+
+    fn point(&self, idx: usize) -> &[f64] {
+        &self.points[idx * self.dim..(idx + 1) * self.dim]
+    }
+
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let mut lower = vec![f64::INFINITY; self.dim];
+        let mut upper = vec![f64::NEG_INFINITY; self.dim];
+        for idx in &self.indices[start..end] {
+            for (d, v) in self.point(*idx).iter().enumerate() {
+                lower[d] = lower[d].min(*v);
+                upper[d] = upper[d].max(*v);
+            }
+        }
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            start,
+            end,
+            lower,
+            upper,
+            children: None,
+        });
+        if end - start > LEAF_SIZE {
+            // split at the median of the widest dimension
+            let node_ref = &self.nodes[node];
+            let axis = (0..self.dim)
+                .max_by(|a, b| {
+                    (node_ref.upper[*a] - node_ref.lower[*a])
+                        .total_cmp(&(node_ref.upper[*b] - node_ref.lower[*b]))
+                })
+                .unwrap_or(0);
+            let mid = start + (end - start) / 2;
+            let (points, dim) = (&self.points, self.dim);
+            self.indices[start..end].select_nth_unstable_by(mid - start, |a, b| {
+                points[a * dim + axis].total_cmp(&points[b * dim + axis])
+            });
+            let left = self.build(start, mid);
+            let right = self.build(mid, end);
+            self.nodes[node].children = Some((left, right));
+        }
+        node
+    }
+
+    /// Smallest and largest distance between a query and any point of a node's bounding box
+    fn box_distance(&self, node: &Node, query: &[f64]) -> (f64, f64) {
+        let gaps = query
+            .iter()
+            .enumerate()
+            .map(|(d, q)| (node.lower[d] - q).max(q - node.upper[d]).max(0.0));
+        let spans = query
+            .iter()
+            .enumerate()
+            .map(|(d, q)| (q - node.lower[d]).abs().max((node.upper[d] - q).abs()));
+        (self.metric.distance(gaps), self.metric.distance(spans))
+    }
+
+    fn distance(&self, idx: usize, query: &[f64]) -> f64 {
+        self.metric.distance(
+            self.point(idx)
+                .iter()
+                .zip(query)
+                .map(|(a, b)| (a - b).abs()),
+        )
+    }
+
+    /// Returns the `k` nearest neighbours of a sample (excluding itself) as `(distance, index)`
+    /// pairs sorted by increasing distance
+    pub(crate) fn nearest(&self, sample: usize, k: usize) -> Vec<(f64, usize)> {
+        let query = self.point(sample).to_vec();
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        let mut stack = vec![0];
+        while let Some(node_idx) = stack.pop() {
+            let node = &self.nodes[node_idx];
+            let (near, _) = self.box_distance(node, &query);
+            if heap.len() == k && heap.peek().is_some_and(|c: &Candidate| near > c.0) {
+                continue;
+            }
+            match node.children {
+                Some((left, right)) => {
+                    // visit the closer child first
+                    let (dl, _) = self.box_distance(&self.nodes[left], &query);
+                    let (dr, _) = self.box_distance(&self.nodes[right], &query);
+                    if dl <= dr {
+                        stack.push(right);
+                        stack.push(left);
+                    } else {
+                        stack.push(left);
+                        stack.push(right);
+                    }
+                }
+                None => {
+                    for idx in &self.indices[node.start..node.end] {
+                        if *idx == sample {
+                            continue;
+                        }
+                        let dist = self.distance(*idx, &query);
+                        if heap.len() < k {
+                            heap.push(Candidate(dist, *idx));
+                        } else if heap.peek().is_some_and(|c| dist < c.0) {
+                            heap.pop();
+                            heap.push(Candidate(dist, *idx));
+                        }
+                    }
+                }
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Candidate(dist, idx)| (dist, idx))
+            .collect()
+    }
+
+    /// Counts the points within a radius of a query (including the query if it is a sample)
+    ///
+    /// Points exactly at the radius are only counted if `inclusive` is set.
+    pub(crate) fn count_within(
+        &self,
+        query: ArrayView1<f64>,
+        radius: f64,
+        inclusive: bool,
+    ) -> usize {
+        let query: Vec<f64> = query.iter().copied().collect();
+        let within = |dist: f64| {
+            if inclusive {
+                dist <= radius
+            } else {
+                dist < radius
+            }
+        };
+        let mut count = 0;
+        let mut stack = vec![0];
+        while let Some(node_idx) = stack.pop() {
+            let node = &self.nodes[node_idx];
+            let (near, far) = self.box_distance(node, &query);
+            if !within(near) {
+                continue;
+            }
+            if within(far) {
+                count += node.end - node.start;
+                continue;
+            }
+            match node.children {
+                Some((left, right)) => {
+                    stack.push(left);
+                    stack.push(right);
+                }
+                None => {
+                    count += self.indices[node.start..node.end]
+                        .iter()
+                        .filter(|idx| within(self.distance(**idx, &query)))
+                        .count();
+                }
+            }
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{KdTree, Metric};
+    use ndarray::Array2;
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    #[test]
+    fn test_brute_force_agreement() {
+        for metric in [Metric::Chebyshev, Metric::Euclidean] {
+            brute_force_agreement(metric);
+        }
+    }
+
+    fn brute_force_agreement(metric: Metric) {
+        let samples = Array2::random((500, 3), Uniform::new(-1.0, 1.0));
+        let tree = KdTree::new(&samples, metric);
+        let distance = |i: usize, j: usize| {
+            let diffs = samples
+                .row(i)
+                .iter()
+                .zip(samples.row(j).iter())
+                .map(|(a, b)| (a - b).abs())
+                .collect::<Vec<f64>>();
+            metric.distance(diffs.into_iter())
+        };
+        for i in (0..samples.nrows()).step_by(7) {
+            let mut brute: Vec<f64> = (0..samples.nrows())
+                .filter(|j| *j != i)
+                .map(|j| distance(i, j))
+                .collect();
+            brute.sort_by(f64::total_cmp);
+
+            let nearest = tree.nearest(i, 5);
+            for (idx, (dist, j)) in nearest.iter().enumerate() {
+                assert_eq!(*dist, brute[idx]);
+                assert_eq!(*dist, distance(i, *j));
+            }
+
+            let radius = nearest[4].0;
+            let strict = brute.iter().filter(|d| **d < radius).count();
+            let inclusive = brute.iter().filter(|d| **d <= radius).count();
+            assert_eq!(tree.count_within(samples.row(i), radius, false), strict + 1);
+            assert_eq!(
+                tree.count_within(samples.row(i), radius, true),
+                inclusive + 1
+            );
+        }
+    }
+}
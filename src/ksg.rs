@@ -0,0 +1,313 @@
+use crate::{
+    knn::{validate_samples, KdTree, Metric},
+    special::digamma,
+};
+use anyhow::{bail, Result};
+use ndarray::{concatenate, Array2, Axis};
+
+/// # KSG Variant
+/// The two estimators proposed by Kraskov, Stögbauer and Grassberger (2004).
+///
+/// * [`First`](KsgVariant::First): marginal neighbours are counted strictly within the distance
+///   to the `k`-th joint neighbour.
+/// * [`Second`](KsgVariant::Second): marginal neighbours are counted within the marginal extent
+///   of the `k` joint neighbours, which has lower bias but higher variance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KsgVariant {
+    #[default]
+    First,
+    Second,
+}
+
+/// # KSG Mutual Information
+/// <https://arxiv.org/abs/cond-mat/0305641>
+///
+/// Calculates the Kraskov–Stögbauer–Grassberger `k`-nearest-neighbour estimate of the mutual
+/// information between two continuous random variables measured in nats.
+///
+/// This is the continuous counterpart of [`mutual_information()`](crate::mutual_information())
+/// and does not require discretizing the samples. Each matrix holds one sample per row and one
+/// dimension per column, and both must have the same number of rows.
+///
+/// Neighbours are searched in the joint space with the maximum norm. For each sample `i` let
+/// `nx(i)` and `ny(i)` be the number of other samples within the neighbourhood in each marginal
+/// space, then the two variants are calculated as follows:
+/// ```math
+/// I1(X;Y) = ψ(k) + ψ(N) - < ψ(nx + 1) + ψ(ny + 1) >
+/// I2(X;Y) = ψ(k) - 1/k + ψ(N) - < ψ(nx) + ψ(ny) >
+/// ```
+///
+/// Heavily tied data should have a small amount of noise added first.
+///
+/// # Errors
+/// If the matrices do not have the same number of rows or have no columns, if `k` is zero or not
+/// smaller than the number of samples, or if a sample coincides with its `k`-th joint neighbour.
+///
+/// # Usage
+/// ```
+/// use ndarray::Array2;
+/// use ndarray_rand::{RandomExt, rand_distr::StandardNormal};
+/// use information::{ksg_mutual_information, KsgVariant};
+///
+/// // correlated bivariate normal with ρ = 0.6
+/// let rho: f64 = 0.6;
+/// let x = Array2::<f64>::random((2000, 1), StandardNormal);
+/// let e = Array2::<f64>::random((2000, 1), StandardNormal);
+/// let y = &x * rho + &e * (1.0 - rho * rho).sqrt();
+///
+/// let expected = -0.5 * (1.0 - rho * rho).ln();
+/// let mi = ksg_mutual_information(&x, &y, 3, KsgVariant::First).unwrap();
+/// assert!((mi - expected).abs() < 0.1);
+/// ```
+pub fn ksg_mutual_information(
+    x: &Array2<f64>,
+    y: &Array2<f64>,
+    k: usize,
+    variant: KsgVariant,
+) -> Result<f64> {
+    let n = validate_samples(&[x, y], k)?;
+    let xy = concatenate![Axis(1), *x, *y];
+    let tree_xy = KdTree::new(&xy, Metric::Chebyshev);
+    let tree_x = KdTree::new(x, Metric::Chebyshev);
+    let tree_y = KdTree::new(y, Metric::Chebyshev);
+
+    let mut marginal_sum = 0.0;
+    for i in 0..n {
+        let neighbours = tree_xy.nearest(i, k);
+        let eps = neighbours[k - 1].0;
+        if eps == 0.0 {
+            bail!("Samples must be distinct from their k-th nearest neighbour");
+        }
+        marginal_sum += match variant {
+            KsgVariant::First => {
+                let nx = tree_x.count_within(x.row(i), eps, false) - 1;
+                let ny = tree_y.count_within(y.row(i), eps, false) - 1;
+                digamma(nx as f64 + 1.0) + digamma(ny as f64 + 1.0)
+            }
+            KsgVariant::Second => {
+                let (eps_x, eps_y) = neighbours
+                    .iter()
+                    .fold((0.0_f64, 0.0_f64), |(ex, ey), (_, j)| {
+                        (ex.max(max_norm(x, i, *j)), ey.max(max_norm(y, i, *j)))
+                    });
+                let nx = tree_x.count_within(x.row(i), eps_x, true) - 1;
+                let ny = tree_y.count_within(y.row(i), eps_y, true) - 1;
+                digamma(nx as f64) + digamma(ny as f64)
+            }
+        };
+    }
+
+    let k = k as f64;
+    let n = n as f64;
+    let mi = match variant {
+        KsgVariant::First => digamma(k) + digamma(n) - marginal_sum / n,
+        KsgVariant::Second => digamma(k) - 1.0 / k + digamma(n) - marginal_sum / n,
+    };
+    Ok(mi)
+}
+
+/// # KSG Conditional Mutual Information
+/// <https://doi.org/10.1103/PhysRevLett.99.204101>
+///
+/// Calculates the Frenzel–Pompe `k`-nearest-neighbour estimate of the conditional mutual
+/// information between two continuous random variables given a third, measured in nats.
+///
+/// This is the continuous counterpart of
+/// [`conditional_mutual_information()`](crate::conditional_mutual_information()) and extends the
+/// first variant of [`ksg_mutual_information()`]. Each matrix holds one sample per row and any
+/// number of dimensions as columns, and all must have the same number of rows.
+///
+/// Neighbours are searched in the joint space with the maximum norm. For each sample `i` let
+/// `nxz(i)`, `nyz(i)` and `nz(i)` be the number of other samples strictly within the distance to
+/// its `k`-th joint neighbour in each subspace, then:
+/// ```math
+/// I(X;Y|Z) = ψ(k) - < ψ(nxz + 1) + ψ(nyz + 1) - ψ(nz + 1) >
+/// ```
+///
+/// # Errors
+/// If the matrices do not have the same number of rows or have no columns, if `k` is zero or not
+/// smaller than the number of samples, or if a sample coincides with its `k`-th joint neighbour.
+///
+/// # Usage
+/// ```
+/// use ndarray::Array2;
+/// use ndarray_rand::{RandomExt, rand_distr::StandardNormal};
+/// use information::{ksg_conditional_mutual_information, ksg_mutual_information, KsgVariant};
+///
+/// // X and Y are both noisy copies of Z
+/// let z = Array2::<f64>::random((2000, 1), StandardNormal);
+/// let x = &z + &Array2::<f64>::random((2000, 1), StandardNormal);
+/// let y = &z + &Array2::<f64>::random((2000, 1), StandardNormal);
+///
+/// // Measures: I(X;Y) > 0 but I(X;Y|Z) = 0
+/// let mi = ksg_mutual_information(&x, &y, 4, KsgVariant::First).unwrap();
+/// let cmi = ksg_conditional_mutual_information(&x, &y, &z, 4).unwrap();
+/// assert!(mi > 0.1);
+/// assert!(cmi.abs() < 0.05);
+/// ```
+pub fn ksg_conditional_mutual_information(
+    x: &Array2<f64>,
+    y: &Array2<f64>,
+    z: &Array2<f64>,
+    k: usize,
+) -> Result<f64> {
+    let n = validate_samples(&[x, y, z], k)?;
+    let xz = concatenate![Axis(1), *x, *z];
+    let yz = concatenate![Axis(1), *y, *z];
+    let xyz = concatenate![Axis(1), *x, *y, *z];
+    let tree_xyz = KdTree::new(&xyz, Metric::Chebyshev);
+    let tree_xz = KdTree::new(&xz, Metric::Chebyshev);
+    let tree_yz = KdTree::new(&yz, Metric::Chebyshev);
+    let tree_z = KdTree::new(z, Metric::Chebyshev);
+
+    let mut marginal_sum = 0.0;
+    for i in 0..n {
+        let eps = tree_xyz.nearest(i, k)[k - 1].0;
+        if eps == 0.0 {
+            bail!("Samples must be distinct from their k-th nearest neighbour");
+        }
+        let nxz = tree_xz.count_within(xz.row(i), eps, false) - 1;
+        let nyz = tree_yz.count_within(yz.row(i), eps, false) - 1;
+        let nz = tree_z.count_within(z.row(i), eps, false) - 1;
+        marginal_sum +=
+            digamma(nxz as f64 + 1.0) + digamma(nyz as f64 + 1.0) - digamma(nz as f64 + 1.0);
+    }
+    Ok(digamma(k as f64) - marginal_sum / n as f64)
+}
+
+/// Maximum norm distance between two rows of a sample matrix
+fn max_norm(samples: &Array2<f64>, i: usize, j: usize) -> f64 {
+    samples
+        .row(i)
+        .iter()
+        .zip(samples.row(j).iter())
+        .fold(0.0_f64, |acc, (a, b)| acc.max((a - b).abs()))
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{ksg_conditional_mutual_information, ksg_mutual_information, KsgVariant};
+    use approx::assert_relative_eq;
+    use ndarray::{array, concatenate, Array2, Axis};
+    use ndarray_rand::{
+        rand_distr::{StandardNormal, Uniform},
+        RandomExt,
+    };
+
+    const N_SAMPLES: usize = 2000;
+    const VARIANTS: [KsgVariant; 2] = [KsgVariant::First, KsgVariant::Second];
+
+    fn correlated(rho: f64, dim: usize) -> (Array2<f64>, Array2<f64>) {
+        let x = Array2::<f64>::random((N_SAMPLES, dim), StandardNormal);
+        let e = Array2::<f64>::random((N_SAMPLES, dim), StandardNormal);
+        let y = &x * rho + &e * (1.0 - rho * rho).sqrt();
+        (x, y)
+    }
+
+    #[test]
+    fn test_gaussian() {
+        for rho in [0.3, 0.6, 0.9] {
+            let (x, y) = correlated(rho, 1);
+            let expected = -0.5 * (1.0 - rho * rho).ln();
+            for variant in VARIANTS {
+                let mi = ksg_mutual_information(&x, &y, 4, variant).unwrap();
+                assert_relative_eq!(mi, expected, epsilon = 0.1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multivariate_gaussian() {
+        // independent pairs of correlated components add up
+        let rho: f64 = 0.5;
+        let (x, y) = correlated(rho, 2);
+        let expected = -(1.0 - rho * rho).ln();
+        for variant in VARIANTS {
+            let mi = ksg_mutual_information(&x, &y, 4, variant).unwrap();
+            assert_relative_eq!(mi, expected, epsilon = 0.12);
+        }
+    }
+
+    #[test]
+    fn test_independent() {
+        let x = Array2::random((N_SAMPLES, 1), Uniform::new(0.0, 1.0));
+        let y = Array2::random((N_SAMPLES, 1), Uniform::new(0.0, 1.0));
+        for variant in VARIANTS {
+            let mi = ksg_mutual_information(&x, &y, 3, variant).unwrap();
+            assert!(mi.abs() < 0.08);
+        }
+    }
+
+    #[test]
+    fn test_symmetry() {
+        let (x, y) = correlated(0.7, 1);
+        for variant in VARIANTS {
+            assert_relative_eq!(
+                ksg_mutual_information(&x, &y, 3, variant).unwrap(),
+                ksg_mutual_information(&y, &x, 3, variant).unwrap(),
+                epsilon = 1e-12
+            );
+        }
+        let xy = concatenate![Axis(1), x, y];
+        assert!(ksg_mutual_information(&xy, &x, 3, KsgVariant::First).unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let x = Array2::zeros((10, 1));
+        let y = Array2::zeros((9, 1));
+        assert!(ksg_mutual_information(&x, &y, 3, KsgVariant::First).is_err());
+        assert!(ksg_mutual_information(&x, &x, 0, KsgVariant::First).is_err());
+        assert!(ksg_mutual_information(&x, &x, 10, KsgVariant::First).is_err());
+        assert!(ksg_mutual_information(&x, &Array2::zeros((10, 0)), 3, KsgVariant::First).is_err());
+    }
+
+    #[test]
+    fn test_duplicates() {
+        // the first two samples coincide, leaving no neighbourhood around them
+        let x = array![[0.0], [0.0], [1.0], [2.0], [3.0], [4.0]];
+        let y = array![[0.0], [0.0], [1.5], [2.5], [3.1], [4.2]];
+        for variant in VARIANTS {
+            assert!(ksg_mutual_information(&x, &y, 1, variant).is_err());
+            assert!(ksg_mutual_information(&x, &y, 2, variant).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_conditional_duplicates() {
+        let x = array![[0.0], [0.0], [1.0], [2.0], [3.0], [4.0]];
+        let y = array![[0.0], [0.0], [1.5], [2.5], [3.1], [4.2]];
+        assert!(ksg_conditional_mutual_information(&x, &y, &x, 1).is_err());
+        assert!(ksg_conditional_mutual_information(&x, &y, &x, 2).is_ok());
+    }
+
+    #[test]
+    fn test_conditional_gaussian() {
+        // X = Z + e1 and Y = Z + e2 with corr(e1, e2) = ρ, so I(X;Y|Z) = -1/2 ln[ 1 - ρ² ]
+        for rho in [0.0, 0.5, 0.8] {
+            let z = Array2::<f64>::random((N_SAMPLES, 1), StandardNormal);
+            let (e1, e2) = correlated(rho, 1);
+            let x = &z + &e1;
+            let y = &z + &e2;
+            let expected = -0.5 * (1.0 - rho * rho).ln();
+            let cmi = ksg_conditional_mutual_information(&x, &y, &z, 4).unwrap();
+            assert_relative_eq!(cmi, expected, epsilon = 0.1);
+        }
+    }
+
+    #[test]
+    fn test_conditional_multivariate() {
+        // Z is two-dimensional and X, Y each depend on a different component
+        let z = Array2::<f64>::random((N_SAMPLES, 2), StandardNormal);
+        let x = &z.column(0).insert_axis(Axis(1))
+            + &Array2::<f64>::random((N_SAMPLES, 1), StandardNormal);
+        let y = &z.column(1).insert_axis(Axis(1))
+            + &Array2::<f64>::random((N_SAMPLES, 1), StandardNormal);
+        let cmi = ksg_conditional_mutual_information(&x, &y, &z, 4).unwrap();
+        assert!(cmi.abs() < 0.1);
+
+        let z_invalid = Array2::<f64>::zeros((N_SAMPLES - 1, 2));
+        assert!(ksg_conditional_mutual_information(&x, &y, &z_invalid, 4).is_err());
+    }
+}
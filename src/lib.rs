@@ -5,33 +5,175 @@
 //! * [`entropy()`]
 //! * [`joint_entropy!()`]
 //! * [`conditional_entropy()`]
+//! * [`renyi_entropy()`]
+//! * [`tsallis_entropy()`]
 //!
 //! ## Information Functions
 //! * [`mutual_information()`]
 //! * [`conditional_mutual_information()`]
+//! * [`jensen_shannon_divergence()`]
+//! * [`jensen_shannon_distance()`]
+//! * [`renyi_mutual_information()`]
+//! * [`total_correlation()`]
+//! * [`dual_total_correlation()`]
+//! * [`o_information()`]
+//! * [`s_information()`]
+//! * [`o_information_subsets()`]
+//! * [`interaction_information()`]
+//! * [`co_information()`]
+//!
+//! ## Partial Information Decomposition
+//! * [`Pid`]
+//! * [`williams_beer_pid()`]
+//! * [`broja_pid()`]
+//! * [`BrojaOptions`]
+//! * [`BrojaDiagnostics`]
+//!
+//! ## Continuous Estimators
+//! * [`ksg_mutual_information()`]
+//! * [`ksg_conditional_mutual_information()`]
+//! * [`kozachenko_leonenko_entropy()`]
+//! * [`discrete_continuous_mutual_information()`]
+//! * [`mixed_mutual_information()`]
+//! * [`gaussian_copula_mutual_information()`]
+//! * [`adaptive_mutual_information()`]
+//! * [`kde_entropy()`]
+//! * [`kde_mutual_information()`]
+//! * [`Bandwidth`]
+//!
+//! ## Gaussian Functions
+//! * [`gaussian_entropy()`]
+//! * [`gaussian_mutual_information()`]
+//! * [`gaussian_conditional_mutual_information()`]
+//! * [`gaussian_total_correlation()`]
+//!
+//! ## Divergence Functions
+//! * [`kl_divergence()`]
+//! * [`cross_entropy()`]
+//! * [`renyi_divergence()`]
+//!
+//! ## Bias-Corrected Estimators
+//! * [`EntropyEstimator`]
+//! * [`estimate_entropy()`]
+//! * [`estimate_conditional_entropy()`]
+//! * [`estimate_mutual_information()`]
+//! * [`estimate_conditional_mutual_information()`]
+//! * [`nsb_entropy()`]
+//!
+//! ## Units
+//! Every function measures information in nats. The [`Unit`] enum converts to bits, bans or an
+//! arbitrary logarithm base, either through [`Unit::convert`] or the following functions:
+//! * [`entropy_in()`]
+//! * [`joint_entropy!()`] with a second argument
+//! * [`conditional_entropy_in()`]
+//! * [`mutual_information_in()`]
+//! * [`conditional_mutual_information_in()`]
 //!
 //! ## Utility
 //! ### `N-d` Histogram
 //! * [`hist1d`]
 //! * [`hist2d`]
 //! * [`hist3d`]
+//! * [`histnd`]
 //!
 //! ### `N-d` Probability
 //! * [`prob1d`]
 //! * [`prob2d`]
 //! * [`prob3d`]
+//! * [`probnd`]
+//!
+//! ### Smoothed Probability
+//! * [`Pseudocount`]
+//! * [`smooth_hist`]
+//! * [`smooth_prob1d`]
+//! * [`smooth_prob2d`]
+//! * [`smooth_prob3d`]
+//! * [`smooth_probnd`]
+//!
+//! ### Shrinkage Probability
+//! * [`shrinkage_intensity`]
+//! * [`shrink_hist`]
+//! * [`shrink_prob1d`]
+//! * [`shrink_prob2d`]
+//! * [`shrink_prob3d`]
+//! * [`shrink_probnd`]
+//!
+//! ### Discretization
+//! * [`equal_width`]
+//! * [`equal_frequency`]
+//! * [`BinRule`]
+//! * [`mdl_discretize`]
+//!
+//! ### Sparse Histogram
+//! * [`SparseHist`]
+//! * [`sparse_hist`]
 //!
+pub mod adaptive;
 pub mod cmi;
 pub mod conditional;
+pub mod copula;
+pub mod differential;
+pub mod discretize;
+pub mod divergence;
 pub mod entropy;
+pub mod estimator;
+pub mod gaussian;
 pub mod hist;
+pub mod interaction;
 pub mod joint;
+pub mod kde;
+pub mod knn;
+pub mod ksg;
+pub mod mixed;
+pub mod multivariate;
 pub mod mutual;
+pub mod nsb;
+pub mod pid;
 pub mod prob;
+pub mod renyi;
+pub mod shrink;
+pub mod smooth;
+pub mod sparse;
+mod special;
+pub mod unit;
 
-pub use cmi::conditional_mutual_information;
-pub use conditional::conditional_entropy;
-pub use entropy::entropy;
-pub use hist::{hist1d, hist2d, hist3d};
-pub use mutual::mutual_information;
-pub use prob::{prob1d, prob2d, prob3d};
+pub use adaptive::adaptive_mutual_information;
+pub use cmi::{conditional_mutual_information, conditional_mutual_information_in};
+pub use conditional::{conditional_entropy, conditional_entropy_in};
+pub use copula::gaussian_copula_mutual_information;
+pub use differential::kozachenko_leonenko_entropy;
+pub use discretize::{equal_frequency, equal_width, mdl_discretize, BinRule};
+pub use divergence::{cross_entropy, kl_divergence};
+pub use entropy::{entropy, entropy_in};
+pub use estimator::{
+    estimate_conditional_entropy, estimate_conditional_mutual_information, estimate_entropy,
+    estimate_mutual_information, EntropyEstimator,
+};
+pub use gaussian::{
+    gaussian_conditional_mutual_information, gaussian_entropy, gaussian_mutual_information,
+    gaussian_total_correlation,
+};
+pub use hist::{hist1d, hist2d, hist3d, histnd};
+pub use interaction::{co_information, interaction_information};
+pub use kde::{kde_entropy, kde_mutual_information, Bandwidth};
+pub use knn::Metric;
+pub use ksg::{ksg_conditional_mutual_information, ksg_mutual_information, KsgVariant};
+pub use mixed::{discrete_continuous_mutual_information, mixed_mutual_information};
+pub use multivariate::{
+    dual_total_correlation, o_information, o_information_subsets, s_information, total_correlation,
+};
+pub use mutual::{
+    jensen_shannon_distance, jensen_shannon_divergence, mutual_information, mutual_information_in,
+};
+pub use nsb::{nsb_entropy, NsbEstimate};
+pub use pid::{broja_pid, williams_beer_pid, BrojaDiagnostics, BrojaOptions, Pid};
+pub use prob::{prob1d, prob2d, prob3d, probnd};
+pub use renyi::{renyi_divergence, renyi_entropy, renyi_mutual_information, tsallis_entropy};
+pub use shrink::{
+    shrink_hist, shrink_prob1d, shrink_prob2d, shrink_prob3d, shrink_probnd, shrinkage_intensity,
+};
+pub use smooth::{
+    smooth_hist, smooth_prob1d, smooth_prob2d, smooth_prob3d, smooth_probnd, Pseudocount,
+};
+pub use sparse::{sparse_hist, SparseHist};
+pub use unit::Unit;
@@ -0,0 +1,234 @@
+use crate::{
+    knn::{validate_samples, KdTree, Metric},
+    special::digamma,
+};
+use anyhow::{bail, Result};
+use ndarray::{concatenate, Array1, Array2, Axis};
+use std::collections::HashMap;
+
+/// # Discrete–Continuous Mutual Information
+/// <https://doi.org/10.1371/journal.pone.0087357>
+///
+/// Calculates the Ross (2014) `k`-nearest-neighbour estimate of the mutual information between a
+/// discrete label and a continuous random variable measured in nats.
+///
+/// The labels are a `usize` array as consumed by [`hist1d()`](crate::hist1d()) and the matrix
+/// holds one continuous sample per row, so the continuous side never needs to be binned.
+///
+/// For each sample `i` with label `l` let `Nl(i)` be the number of samples sharing its label,
+/// `d(i)` the maximum norm distance to its `k`-th nearest neighbour among them and `m(i)` the
+/// number of other samples of any label within `d(i)`, then:
+/// ```math
+/// I(L;X) = ψ(N) - < ψ(Nl) > + ψ(k) - < ψ(m) >
+/// ```
+///
+/// Samples whose label occurs only once are ignored, and labels with `k` or fewer samples use
+/// one neighbour less than their number of samples.
+///
+/// # Errors
+/// If the labels and the matrix do not have the same number of samples, if the matrix has no
+/// columns, if `k` is zero or not smaller than the number of samples, or if every label occurs
+/// only once.
+///
+/// # Usage
+/// ```
+/// use ndarray::{Array1, Array2, Axis};
+/// use ndarray_rand::{RandomExt, rand_distr::Uniform};
+/// use information::discrete_continuous_mutual_information;
+///
+/// // label 0 draws from [0, 1) and label 1 from [2, 3)
+/// let labels = Array1::random(2000, Uniform::new(0, 2));
+/// let noise = Array1::random(2000, Uniform::new(0.0, 1.0));
+/// let x = (labels.mapv(|l| 2.0 * l as f64) + noise).insert_axis(Axis(1));
+///
+/// // Measures: the label is fully determined by X
+/// let mi = discrete_continuous_mutual_information(&labels, &x, 3).unwrap();
+/// assert!((mi - 2.0_f64.ln()).abs() < 0.05);
+/// ```
+pub fn discrete_continuous_mutual_information(
+    labels: &Array1<usize>,
+    x: &Array2<f64>,
+    k: usize,
+) -> Result<f64> {
+    let n = validate_samples(&[x], k)?;
+    if labels.len() != n {
+        bail!("Provided labels must have as many entries as the sample matrix has rows");
+    }
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (i, l) in labels.iter().enumerate() {
+        groups.entry(*l).or_default().push(i);
+    }
+
+    let tree = KdTree::new(x, Metric::Chebyshev);
+    let mut n_used = 0;
+    let mut sum = 0.0;
+    for members in groups.values().filter(|m| m.len() > 1) {
+        let k_label = k.min(members.len() - 1);
+        let subset = x.select(Axis(0), members);
+        let label_tree = KdTree::new(&subset, Metric::Chebyshev);
+        for (local, i) in members.iter().enumerate() {
+            let radius = label_tree.nearest(local, k_label)[k_label - 1].0;
+            let m = tree.count_within(x.row(*i), radius, true) - 1;
+            sum += digamma(k_label as f64) - digamma(members.len() as f64) - digamma(m as f64);
+        }
+        n_used += members.len();
+    }
+    if n_used == 0 {
+        bail!("At least one label must occur more than once");
+    }
+    Ok(digamma(n_used as f64) + sum / n_used as f64)
+}
+
+/// # Mixed Mutual Information
+/// <https://arxiv.org/abs/1709.06212>
+///
+/// Calculates the Gao et al. (2017) `k`-nearest-neighbour estimate of the mutual information
+/// between two random variables that may be continuous, discrete or a mixture of both, measured
+/// in nats.
+///
+/// This extends the first variant of [`ksg_mutual_information()`](crate::ksg_mutual_information())
+/// to samples that repeat with nonzero probability. Each matrix holds one sample per row and
+/// discrete values are simply given as floats.
+///
+/// For each sample `i` let `ρ(i)` be the maximum norm distance to its `k`-th nearest neighbour in
+/// the joint space and `nx(i)`, `ny(i)` the number of other samples strictly within `ρ(i)` in each
+/// marginal space. When `ρ(i) = 0` the sample is repeated: `k(i)` becomes its number of exact
+/// copies (including itself) instead of `k`, and the marginal counts include every sample at
+/// distance zero:
+/// ```math
+/// I(X;Y) = ln[ N ] + < ψ(k(i)) - ψ(nx + 1) - ψ(ny + 1) >
+/// ```
+///
+/// # Errors
+/// If the matrices do not have the same number of rows or have no columns, or if `k` is zero or
+/// not smaller than the number of samples.
+///
+/// # Usage
+/// ```
+/// use ndarray::{Array1, Axis};
+/// use ndarray_rand::{RandomExt, rand_distr::Uniform};
+/// use information::mixed_mutual_information;
+///
+/// // a discrete variable copied into a second one
+/// let x = Array1::random(2000, Uniform::new(0, 4)).mapv(|v| v as f64).insert_axis(Axis(1));
+/// let y = x.clone();
+///
+/// let mi = mixed_mutual_information(&x, &y, 3).unwrap();
+/// assert!((mi - 4.0_f64.ln()).abs() < 0.05);
+/// ```
+pub fn mixed_mutual_information(x: &Array2<f64>, y: &Array2<f64>, k: usize) -> Result<f64> {
+    let n = validate_samples(&[x, y], k)?;
+    let joint = concatenate![Axis(1), *x, *y];
+    let tree_xy = KdTree::new(&joint, Metric::Chebyshev);
+    let tree_x = KdTree::new(x, Metric::Chebyshev);
+    let tree_y = KdTree::new(y, Metric::Chebyshev);
+
+    let sum = (0..n).fold(0.0, |acc, i| {
+        let rho = tree_xy.nearest(i, k)[k - 1].0;
+        // repeated samples are counted at distance zero inclusively
+        let repeated = rho == 0.0;
+        let k_i = if repeated {
+            tree_xy.count_within(joint.row(i), 0.0, true)
+        } else {
+            k
+        };
+        let nx = tree_x.count_within(x.row(i), rho, repeated) - 1;
+        let ny = tree_y.count_within(y.row(i), rho, repeated) - 1;
+        acc + digamma(k_i as f64) - digamma(nx as f64 + 1.0) - digamma(ny as f64 + 1.0)
+    });
+    Ok(sum / n as f64 + (n as f64).ln())
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{discrete_continuous_mutual_information, mixed_mutual_information};
+    use crate::{mutual::mutual_information, prob::prob2d};
+    use approx::assert_relative_eq;
+    use ndarray::{Array1, Array2, Axis};
+    use ndarray_rand::{
+        rand_distr::{StandardNormal, Uniform},
+        RandomExt,
+    };
+    use std::f64::consts::LN_2;
+
+    const N_SAMPLES: usize = 2000;
+
+    /// Labels uniform over `{0, 1}` and a uniform sample of width `width` offset by the label
+    fn shifted_uniform(width: f64) -> (Array1<usize>, Array2<f64>) {
+        let labels = Array1::random(N_SAMPLES, Uniform::new(0, 2));
+        let noise = Array1::random(N_SAMPLES, Uniform::new(0.0, width));
+        let x = labels.mapv(|l| l as f64) + noise;
+        (labels, x.insert_axis(Axis(1)))
+    }
+
+    #[test]
+    fn test_discrete_continuous() {
+        // width 0.5: the label is determined by X, I = ln[2]
+        // width 2.0: X has density 1/4 on [0, 1) and [2, 3) and 1/2 on [1, 2), I = ln[2] / 2
+        for (width, expected) in [(0.5, LN_2), (2.0, LN_2 / 2.0)] {
+            let (labels, x) = shifted_uniform(width);
+            let mi = discrete_continuous_mutual_information(&labels, &x, 3).unwrap();
+            assert_relative_eq!(mi, expected, epsilon = 0.05);
+
+            // the mixed estimator agrees when the labels are given as floats
+            let l = labels.mapv(|l| l as f64).insert_axis(Axis(1));
+            let mi = mixed_mutual_information(&l, &x, 3).unwrap();
+            assert_relative_eq!(mi, expected, epsilon = 0.05);
+        }
+    }
+
+    #[test]
+    fn test_discrete_continuous_independent() {
+        let labels = Array1::random(N_SAMPLES, Uniform::new(0, 4));
+        let x = Array2::random((N_SAMPLES, 2), Uniform::new(0.0, 1.0));
+        let mi = discrete_continuous_mutual_information(&labels, &x, 3).unwrap();
+        assert!(mi.abs() < 0.05);
+    }
+
+    #[test]
+    fn test_discrete_continuous_singletons() {
+        // the singleton label 5 is ignored
+        let mut labels = Array1::random(N_SAMPLES, Uniform::new(0, 2));
+        labels[0] = 5;
+        let x = Array2::random((N_SAMPLES, 1), Uniform::new(0.0, 1.0));
+        assert!(discrete_continuous_mutual_information(&labels, &x, 3).is_ok());
+
+        let labels = Array1::from_iter(0..10);
+        let x = Array2::random((10, 1), Uniform::new(0.0, 1.0));
+        assert!(discrete_continuous_mutual_information(&labels, &x, 3).is_err());
+    }
+
+    #[test]
+    fn test_mixed_discrete() {
+        // on purely discrete samples the estimate matches the plug-in mutual information
+        let x = Array1::random(N_SAMPLES, Uniform::new(0, 4));
+        let y = x.mapv(|v| (v + usize::from(v % 2 == 0)) % 4);
+        let expected = mutual_information(&prob2d(&x, &y, 4, 4).unwrap());
+        let mi = mixed_mutual_information(
+            &x.mapv(|v| v as f64).insert_axis(Axis(1)),
+            &y.mapv(|v| v as f64).insert_axis(Axis(1)),
+            3,
+        )
+        .unwrap();
+        assert_relative_eq!(mi, expected, epsilon = 0.01);
+    }
+
+    #[test]
+    fn test_mixed_gaussian() {
+        let rho: f64 = 0.6;
+        let x = Array2::<f64>::random((N_SAMPLES, 1), StandardNormal);
+        let e = Array2::<f64>::random((N_SAMPLES, 1), StandardNormal);
+        let y = &x * rho + &e * (1.0 - rho * rho).sqrt();
+        let mi = mixed_mutual_information(&x, &y, 4).unwrap();
+        assert_relative_eq!(mi, -0.5 * (1.0 - rho * rho).ln(), epsilon = 0.1);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let labels = Array1::zeros(9);
+        let x = Array2::zeros((10, 1));
+        assert!(discrete_continuous_mutual_information(&labels, &x, 3).is_err());
+        assert!(mixed_mutual_information(&x, &Array2::zeros((9, 1)), 3).is_err());
+    }
+}
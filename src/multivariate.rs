@@ -0,0 +1,426 @@
+use crate::{joint_entropy, prob::probnd};
+use anyhow::{bail, Result};
+use ndarray::{Array, Array1, Array2, ArrayD, Axis, Dimension};
+
+/// Marginal distribution of a probability array over a subset of its axes
+///
+/// The kept axes stay in increasing order and every other axis is summed out.
+pub(crate) fn marginalize<D: Dimension>(p: &Array<f64, D>, keep: &[usize]) -> ArrayD<f64> {
+    let mut marginal = p.view().into_dyn().to_owned();
+    for axis in (0..p.ndim()).rev() {
+        if !keep.contains(&axis) {
+            marginal = marginal.sum_axis(Axis(axis));
+        }
+    }
+    marginal
+}
+
+/// # Total Correlation
+/// <https://en.wikipedia.org/wiki/Total_correlation>
+///
+/// Calculates the total correlation (multi-information) of an `N`-dimensional joint probability
+/// array measured in nats, where each axis is one variable.
+///
+/// This generalizes [`mutual_information()`](crate::mutual_information()) to any number of
+/// variables and measures the total redundancy among them. The marginal distribution of each
+/// variable is derived from the joint array:
+/// ```math
+/// C(X1,...,Xn) = Σ H(Xi) - H(X1,...,Xn)
+/// ```
+///
+/// # Usage
+/// ```
+/// use ndarray::Array3;
+/// use information::total_correlation;
+///
+/// // three copies of a fair coin
+/// let mut p_xyz = Array3::zeros((2, 2, 2));
+/// p_xyz[[0, 0, 0]] = 0.5;
+/// p_xyz[[1, 1, 1]] = 0.5;
+///
+/// let tc = total_correlation(&p_xyz);
+/// assert!((tc - 2.0 * 2.0_f64.ln()).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn total_correlation<D: Dimension>(p: &Array<f64, D>) -> f64 {
+    let marginals = (0..p.ndim())
+        .map(|axis| joint_entropy!(marginalize(p, &[axis])))
+        .sum::<f64>();
+    marginals - joint_entropy!(p)
+}
+
+/// # Dual Total Correlation
+/// <https://en.wikipedia.org/wiki/Dual_total_correlation>
+///
+/// Calculates the dual total correlation (binding information) of an `N`-dimensional joint
+/// probability array measured in nats, where each axis is one variable.
+///
+/// This is the part of the joint entropy shared by at least two variables, which is the joint
+/// entropy minus the entropy each variable holds on its own given all the others. The
+/// distribution of each set of `N - 1` variables is derived from the joint array:
+/// ```math
+/// D(X1,...,Xn) = H(X1,...,Xn) - Σ H(Xi | X1,...,Xi-1,Xi+1,...,Xn)
+///              = Σ H(X1,...,Xi-1,Xi+1,...,Xn) - (n - 1) H(X1,...,Xn)
+/// ```
+///
+/// # Usage
+/// ```
+/// use ndarray::Array3;
+/// use information::dual_total_correlation;
+///
+/// // Z is the exclusive or of two fair coins X and Y
+/// let mut p_xyz = Array3::zeros((2, 2, 2));
+/// for x in 0..2 {
+///     for y in 0..2 {
+///         p_xyz[[x, y, x ^ y]] = 0.25;
+///     }
+/// }
+///
+/// let dtc = dual_total_correlation(&p_xyz);
+/// assert!((dtc - 2.0 * 2.0_f64.ln()).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn dual_total_correlation<D: Dimension>(p: &Array<f64, D>) -> f64 {
+    let n = p.ndim();
+    let leave_one_out = (0..n)
+        .map(|skip| {
+            let keep: Vec<usize> = (0..n).filter(|axis| *axis != skip).collect();
+            joint_entropy!(marginalize(p, &keep))
+        })
+        .sum::<f64>();
+    leave_one_out - n.saturating_sub(1) as f64 * joint_entropy!(p)
+}
+
+/// # O-Information
+/// <https://doi.org/10.1103/PhysRevE.100.032305>
+///
+/// Calculates the O-information (Rosas et al. 2019) of an `N`-dimensional joint probability
+/// array measured in nats, where each axis is one variable.
+///
+/// This is the balance between the [`total_correlation()`] and the
+/// [`dual_total_correlation()`]. A positive value means the variables are dominated by
+/// redundancy and a negative value means they are dominated by synergy:
+/// ```math
+/// Ω(X1,...,Xn) = C(X1,...,Xn) - D(X1,...,Xn)
+///              = (n - 2) H(X1,...,Xn) + Σ [ H(Xi) - H(X1,...,Xi-1,Xi+1,...,Xn) ]
+/// ```
+///
+/// # Usage
+/// ```
+/// use ndarray::Array3;
+/// use information::o_information;
+///
+/// // three copies of a fair coin are purely redundant
+/// let mut p_copy = Array3::zeros((2, 2, 2));
+/// p_copy[[0, 0, 0]] = 0.5;
+/// p_copy[[1, 1, 1]] = 0.5;
+/// assert!(o_information(&p_copy) > 0.0);
+///
+/// // the exclusive or of two fair coins is purely synergistic
+/// let mut p_xor = Array3::zeros((2, 2, 2));
+/// for x in 0..2 {
+///     for y in 0..2 {
+///         p_xor[[x, y, x ^ y]] = 0.25;
+///     }
+/// }
+/// assert!(o_information(&p_xor) < 0.0);
+/// ```
+#[must_use]
+pub fn o_information<D: Dimension>(p: &Array<f64, D>) -> f64 {
+    total_correlation(p) - dual_total_correlation(p)
+}
+
+/// # S-Information
+/// <https://doi.org/10.1103/PhysRevE.100.032305>
+///
+/// Calculates the S-information of an `N`-dimensional joint probability array measured in nats,
+/// where each axis is one variable.
+///
+/// This is the sum of the [`total_correlation()`] and the [`dual_total_correlation()`], which
+/// measures the overall strength of the dependencies regardless of their kind:
+/// ```math
+/// Σ(X1,...,Xn) = C(X1,...,Xn) + D(X1,...,Xn) = Σ I(Xi; X1,...,Xi-1,Xi+1,...,Xn)
+/// ```
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{mutual_information, s_information};
+///
+/// let p_xy = array![[0.4, 0.1], [0.1, 0.4]];
+/// let s = s_information(&p_xy);
+/// assert!((s - 2.0 * mutual_information(&p_xy)).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn s_information<D: Dimension>(p: &Array<f64, D>) -> f64 {
+    total_correlation(p) + dual_total_correlation(p)
+}
+
+/// Every subset of `k` indices out of `0..n` in lexicographic order
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    let mut subsets = Vec::new();
+    let mut current: Vec<usize> = (0..k).collect();
+    loop {
+        subsets.push(current.clone());
+        // advance the rightmost index that has room to move
+        let Some(pos) = (0..k).rev().find(|i| current[*i] < n - k + i) else {
+            return subsets;
+        };
+        current[pos] += 1;
+        for i in pos + 1..k {
+            current[i] = current[i - 1] + 1;
+        }
+    }
+}
+
+/// # O-Information of Subsets
+/// Calculates the [`o_information()`] of every subset of `k` variables of a sample matrix,
+/// where each column is one discrete variable and each row one observation.
+///
+/// The number of bins of each variable is one more than the largest value of its column. The
+/// subsets are returned in lexicographic order of their column indices along with their
+/// O-information.
+///
+/// # Errors
+/// If `k` is zero or larger than the number of columns, or if the matrix has no rows.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::o_information_subsets;
+///
+/// // the third column is the exclusive or of the first two and the fourth copies the first
+/// let samples = array![
+///     [0, 0, 0, 0],
+///     [0, 1, 1, 0],
+///     [1, 0, 1, 1],
+///     [1, 1, 0, 1],
+/// ];
+/// let subsets = o_information_subsets(&samples, 3).unwrap();
+/// assert_eq!(subsets.len(), 4);
+/// assert_eq!(subsets[0].0, vec![0, 1, 2]);
+/// assert!(subsets[0].1 < 0.0);
+/// ```
+pub fn o_information_subsets(samples: &Array2<usize>, k: usize) -> Result<Vec<(Vec<usize>, f64)>> {
+    let ncols = samples.ncols();
+    if k == 0 || k > ncols {
+        bail!("The subset size must be between one and the number of columns");
+    }
+    if samples.nrows() == 0 {
+        bail!("Provided samples must have at least one row");
+    }
+    let columns: Vec<Array1<usize>> = samples
+        .columns()
+        .into_iter()
+        .map(|c| c.to_owned())
+        .collect();
+    let nbins: Vec<usize> = columns
+        .iter()
+        .map(|c| c.iter().max().map_or(1, |m| m + 1))
+        .collect();
+
+    combinations(ncols, k)
+        .into_iter()
+        .map(|subset| {
+            let arrs: Vec<Array1<usize>> = subset.iter().map(|i| columns[*i].clone()).collect();
+            let bins: Vec<usize> = subset.iter().map(|i| nbins[*i]).collect();
+            let p = probnd(&arrs, &bins)?;
+            Ok((subset, o_information(&p)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{
+        combinations, dual_total_correlation, marginalize, o_information, o_information_subsets,
+        s_information, total_correlation,
+    };
+    use crate::{joint_entropy, mutual::mutual_information, prob::probnd};
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1, Array2, Array3, Axis};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+    use std::f64::consts::LN_2;
+
+    const N_ITER: usize = 100;
+    const ARRAY_SIZE: usize = 100;
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn test_marginalize() {
+        let p = array![[[0.1, 0.2], [0.0, 0.1]], [[0.3, 0.1], [0.15, 0.05]]];
+        let p_x = marginalize(&p, &[0]);
+        assert_relative_eq!(p_x[[0]], 0.4, epsilon = EPSILON);
+        assert_relative_eq!(p_x[[1]], 0.6, epsilon = EPSILON);
+
+        let p_xz = marginalize(&p, &[0, 2]);
+        assert_eq!(p_xz.shape(), &[2, 2]);
+        let expected = p.sum_axis(Axis(1));
+        for (a, b) in p_xz.iter().zip(expected.iter()) {
+            assert_relative_eq!(a, b, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_bivariate() {
+        // both reduce to the mutual information for two variables
+        for _ in 0..N_ITER {
+            let c_xy = Array2::random((4, 5), Uniform::new(0.0, 1.0));
+            let p_xy = &c_xy / c_xy.sum();
+            let mi = mutual_information(&p_xy);
+            assert_relative_eq!(total_correlation(&p_xy), mi, epsilon = EPSILON);
+            assert_relative_eq!(dual_total_correlation(&p_xy), mi, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_trivariate() {
+        for _ in 0..N_ITER {
+            let c_xyz = Array3::random((3, 4, 5), Uniform::new(0.0, 1.0));
+            let p_xyz = &c_xyz / c_xyz.sum();
+
+            // Measures: C(X,Y,Z) = I(X;Y) + I(X,Y;Z)
+            let p_xy = p_xyz.sum_axis(Axis(2));
+            let p_xy_z = p_xyz.clone().into_shape((12, 5)).unwrap();
+            let expected = mutual_information(&p_xy) + mutual_information(&p_xy_z);
+            assert_relative_eq!(total_correlation(&p_xyz), expected, epsilon = EPSILON);
+
+            // Measures: D(X,Y,Z) = H(X,Y,Z) - H(X|Y,Z) - H(Y|X,Z) - H(Z|X,Y)
+            let h_xyz = joint_entropy!(&p_xyz);
+            let h = |keep: &[usize]| joint_entropy!(marginalize(&p_xyz, keep));
+            let expected =
+                h_xyz - (h_xyz - h(&[1, 2])) - (h_xyz - h(&[0, 2])) - (h_xyz - h(&[0, 1]));
+            assert_relative_eq!(dual_total_correlation(&p_xyz), expected, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_xor_and_copy() {
+        let mut p_xor = Array3::zeros((2, 2, 2));
+        let mut p_copy = Array3::zeros((2, 2, 2));
+        for x in 0..2 {
+            for y in 0..2 {
+                p_xor[[x, y, x ^ y]] = 0.25;
+            }
+            p_copy[[x, x, x]] = 0.5;
+        }
+        assert_relative_eq!(total_correlation(&p_xor), LN_2, epsilon = EPSILON);
+        assert_relative_eq!(
+            dual_total_correlation(&p_xor),
+            2.0 * LN_2,
+            epsilon = EPSILON
+        );
+        assert_relative_eq!(total_correlation(&p_copy), 2.0 * LN_2, epsilon = EPSILON);
+        assert_relative_eq!(dual_total_correlation(&p_copy), LN_2, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_bounds() {
+        for _ in 0..N_ITER {
+            let arrs: Vec<Array1<usize>> = (0..4)
+                .map(|_| Array1::random(ARRAY_SIZE, Uniform::new(0, 3)))
+                .collect();
+            let p = probnd(&arrs, &[3, 3, 3, 3]).unwrap();
+            let tc = total_correlation(&p);
+            let dtc = dual_total_correlation(&p);
+
+            // Measures: 0 <= C <= (n - 1) D and 0 <= D <= (n - 1) C
+            assert!(tc >= -EPSILON && dtc >= -EPSILON);
+            assert!(tc <= 3.0 * dtc + EPSILON);
+            assert!(dtc <= 3.0 * tc + EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_independent() {
+        let p_x = array![0.2, 0.8];
+        let p_y = array![0.1, 0.3, 0.6];
+        let p_xy = &p_x.clone().insert_axis(Axis(1)) * &p_y.insert_axis(Axis(0));
+        assert_relative_eq!(total_correlation(&p_xy), 0.0, epsilon = EPSILON);
+        assert_relative_eq!(dual_total_correlation(&p_xy), 0.0, epsilon = EPSILON);
+        assert_eq!(total_correlation(&p_x), 0.0);
+        assert_eq!(dual_total_correlation(&p_x), 0.0);
+    }
+
+    #[test]
+    fn test_o_s_information() {
+        let mut p_xor = Array3::zeros((2, 2, 2));
+        let mut p_copy = Array3::zeros((2, 2, 2));
+        for x in 0..2 {
+            for y in 0..2 {
+                p_xor[[x, y, x ^ y]] = 0.25;
+            }
+            p_copy[[x, x, x]] = 0.5;
+        }
+        assert_relative_eq!(o_information(&p_xor), -LN_2, epsilon = EPSILON);
+        assert_relative_eq!(o_information(&p_copy), LN_2, epsilon = EPSILON);
+        assert_relative_eq!(s_information(&p_xor), 3.0 * LN_2, epsilon = EPSILON);
+        assert_relative_eq!(s_information(&p_copy), 3.0 * LN_2, epsilon = EPSILON);
+
+        // O-information vanishes for two variables
+        for _ in 0..N_ITER {
+            let c_xy = Array2::random((4, 5), Uniform::new(0.0, 1.0));
+            let p_xy = &c_xy / c_xy.sum();
+            assert_relative_eq!(o_information(&p_xy), 0.0, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    /// Measures: Σ(X1,...,Xn) = Σ I(Xi; X-i)
+    fn test_s_information_sum() {
+        for _ in 0..N_ITER {
+            let c_xyz = Array3::random((2, 3, 4), Uniform::new(0.0, 1.0));
+            let p_xyz = &c_xyz / c_xyz.sum();
+            let h = |keep: &[usize]| joint_entropy!(marginalize(&p_xyz, keep));
+            let h_xyz = joint_entropy!(&p_xyz);
+            let expected = (h(&[0]) + h(&[1, 2]) - h_xyz)
+                + (h(&[1]) + h(&[0, 2]) - h_xyz)
+                + (h(&[2]) + h(&[0, 1]) - h_xyz);
+            assert_relative_eq!(s_information(&p_xyz), expected, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_combinations() {
+        assert_eq!(
+            combinations(4, 2),
+            vec![
+                vec![0, 1],
+                vec![0, 2],
+                vec![0, 3],
+                vec![1, 2],
+                vec![1, 3],
+                vec![2, 3]
+            ]
+        );
+        assert_eq!(combinations(3, 3), vec![vec![0, 1, 2]]);
+        assert_eq!(combinations(5, 3).len(), 10);
+    }
+
+    #[test]
+    fn test_subsets() {
+        for _ in 0..N_ITER {
+            let samples = Array2::random((ARRAY_SIZE, 5), Uniform::new(0, 3));
+            let subsets = o_information_subsets(&samples, 3).unwrap();
+            assert_eq!(subsets.len(), 10);
+            for (subset, o) in subsets {
+                let arrs: Vec<Array1<usize>> = subset
+                    .iter()
+                    .map(|i| samples.column(*i).to_owned())
+                    .collect();
+                let nbins: Vec<usize> = arrs.iter().map(|a| a.iter().max().unwrap() + 1).collect();
+                let p = probnd(&arrs, &nbins).unwrap();
+                assert_relative_eq!(o, o_information(&p), epsilon = EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_subsets_invalid() {
+        let samples = Array2::<usize>::zeros((10, 3));
+        assert!(o_information_subsets(&samples, 0).is_err());
+        assert!(o_information_subsets(&samples, 4).is_err());
+        assert!(o_information_subsets(&Array2::<usize>::zeros((0, 3)), 2).is_err());
+    }
+}
@@ -1,4 +1,5 @@
-use ndarray::{Array2, Axis, Zip};
+use crate::{entropy::entropy, unit::Unit};
+use ndarray::{Array1, Array2, Axis, Zip};
 
 /// # Mutual Information
 /// <https://en.wikipedia.org/wiki/Mutual_information>
@@ -31,28 +32,144 @@ use ndarray::{Array2, Axis, Zip};
 /// // Measures: I(X;Y) = I(Y;X)
 /// assert_relative_eq!(i_xy, i_yx, epsilon=1e-14);
 /// ```
+///
+/// Also accepts a two-dimensional [`SparseHist`](crate::SparseHist) of counts.
+#[must_use]
+pub fn mutual_information<P: MutualInformation + ?Sized>(p_xy: &P) -> f64 {
+    p_xy.mutual_information()
+}
+
+/// Calculates the [`mutual_information()`] measured in the provided [`Unit`].
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{mutual_information_in, Unit};
+///
+/// let p_xy = array![[0.5, 0.0], [0.0, 0.5]];
+/// assert_eq!(mutual_information_in(&p_xy, Unit::Bits), 1.0);
+/// ```
+#[must_use]
+pub fn mutual_information_in<P: MutualInformation + ?Sized>(p_xy: &P, unit: Unit) -> f64 {
+    unit.convert(p_xy.mutual_information())
+}
+
+/// Distributions accepted by [`mutual_information()`]
+pub trait MutualInformation {
+    /// Calculates the mutual information `I(X;Y)` measured in nats.
+    fn mutual_information(&self) -> f64;
+}
+
+impl MutualInformation for Array2<f64> {
+    fn mutual_information(&self) -> f64 {
+        Zip::from(self)
+            .and_broadcast(&self.sum_axis(Axis(0)))
+            .and_broadcast(&self.sum_axis(Axis(1)).insert_axis(Axis(1)))
+            .fold(0.0, |acc, xy, x, y| {
+                if *xy == 0.0 || *x == 0.0 || *y == 0.0 {
+                    acc
+                } else {
+                    acc + (xy * (xy / (x * y)).ln())
+                }
+            })
+    }
+}
+
+/// # Jensen–Shannon Divergence
+/// <https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence>
+///
+/// Calculates the generalized Jensen–Shannon divergence between `N` probability distributions
+/// stacked as the rows of a matrix, measured in nats.
+///
+/// The divergence is the entropy of the weighted mixture minus the weighted entropies of the
+/// components. It is also the mutual information between the mixture and the label `Z` of the
+/// component each sample is drawn from:
+/// ```math
+/// JSD(P1, ..., Pn) = H( Σ πi Pi ) - Σ πi H(Pi) = I(X;Z)
+/// ```
+///
+/// If no weights are provided each distribution is weighted equally, otherwise the weights are
+/// normalized by their sum.
+///
+/// # Panics
+/// If the number of weights does not match the number of distributions, if a weight is negative
+/// or not finite, or if all weights are zero.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::jensen_shannon_divergence;
+///
+/// let p = array![[1.0, 0.0], [0.0, 1.0]];
+/// let jsd = jensen_shannon_divergence(&p, None);
+/// assert!((jsd - 2.0_f64.ln()).abs() < 1e-12);
+///
+/// let weights = array![0.75, 0.25];
+/// let jsd = jensen_shannon_divergence(&p, Some(&weights));
+/// assert!((jsd - 0.562_335_144_618_808_7).abs() < 1e-12);
+/// ```
 #[must_use]
-pub fn mutual_information(p_xy: &Array2<f64>) -> f64 {
-    Zip::from(p_xy)
-        .and_broadcast(&p_xy.sum_axis(Axis(0)))
-        .and_broadcast(&p_xy.sum_axis(Axis(1)).insert_axis(Axis(1)))
-        .fold(0.0, |acc, xy, x, y| {
-            if *xy == 0.0 || *x == 0.0 || *y == 0.0 {
-                acc
-            } else {
-                acc + (xy * (xy / (x * y)).ln())
-            }
-        })
+pub fn jensen_shannon_divergence(p: &Array2<f64>, weights: Option<&Array1<f64>>) -> f64 {
+    let n = p.nrows();
+    let weights = match weights {
+        Some(w) => {
+            assert_eq!(
+                w.len(),
+                n,
+                "A weight must be provided for each distribution"
+            );
+            assert!(
+                w.iter().all(|w| w.is_finite() && *w >= 0.0),
+                "Provided weights must be non-negative and finite"
+            );
+            let total = w.sum();
+            assert!(total > 0.0, "Provided weights must not all be zero");
+            w / total
+        }
+        None => Array1::from_elem(n, 1.0 / n as f64),
+    };
+    let mixture = weights.dot(p);
+    let h_components = p
+        .rows()
+        .into_iter()
+        .zip(weights.iter())
+        .fold(0.0, |acc, (row, w)| acc + w * entropy(&row.to_owned()));
+    entropy(&mixture) - h_components
+}
+
+/// # Jensen–Shannon Distance
+/// <https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence#Metric>
+///
+/// Calculates the square root of the [`jensen_shannon_divergence()`], which is a metric between
+/// probability distributions.
+///
+/// # Panics
+/// If the number of weights does not match the number of distributions, if a weight is negative
+/// or not finite, or if all weights are zero.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::jensen_shannon_distance;
+///
+/// let p = array![[0.5, 0.5, 0.0], [0.0, 0.5, 0.5]];
+/// let d = jensen_shannon_distance(&p, None);
+/// assert!((d * d - 0.5 * 2.0_f64.ln()).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn jensen_shannon_distance(p: &Array2<f64>, weights: Option<&Array1<f64>>) -> f64 {
+    jensen_shannon_divergence(p, weights).max(0.0).sqrt()
 }
 
 #[cfg(test)]
 mod testing {
 
-    use super::mutual_information;
+    use super::{jensen_shannon_distance, jensen_shannon_divergence, mutual_information};
     use crate::{conditional::conditional_entropy, entropy::entropy, joint_entropy, prob::prob2d};
     use approx::assert_relative_eq;
-    use ndarray::{Array1, Array2, Axis};
+    use ndarray::{array, stack, Array1, Array2, Axis};
     use ndarray_rand::{rand_distr::Uniform, RandomExt};
+    use std::f64::consts::LN_2;
 
     const N_ITER: usize = 1000;
     const ARRAY_SIZE: usize = 100;
@@ -114,4 +231,81 @@ mod testing {
             assert_relative_eq!(i_xy, h_x + h_y - h_joint_xy, epsilon = EPSILON);
         }
     }
+
+    #[test]
+    /// https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence#Relation_to_mutual_information
+    fn test_jsd_mutual_information() {
+        for _ in 0..N_ITER {
+            let c_p = Array2::random((3, ARRAY_SIZE), Uniform::new(0.1, 0.9));
+            let p = &c_p / &c_p.sum_axis(Axis(1)).insert_axis(Axis(1));
+            let c_w = Array1::random(3, Uniform::new(0.1, 0.9));
+            let w = &c_w / c_w.sum();
+
+            // joint distribution of the component label and the outcome
+            let p_zx = &p * &w.clone().insert_axis(Axis(1));
+            let jsd = jensen_shannon_divergence(&p, Some(&w));
+
+            // Measures: JSD = I(X;Z)
+            assert_relative_eq!(jsd, mutual_information(&p_zx), epsilon = EPSILON);
+
+            // Measures: 0 <= JSD <= H(Z)
+            assert!(jsd >= 0.0);
+            assert!(jsd <= entropy(&w) + EPSILON);
+        }
+    }
+
+    #[test]
+    /// https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence#Metric
+    fn test_js_distance_triangle() {
+        for _ in 0..N_ITER {
+            let c_p = Array2::random((3, ARRAY_SIZE), Uniform::new(0.1, 0.9));
+            let p = &c_p / &c_p.sum_axis(Axis(1)).insert_axis(Axis(1));
+            let pair = |i: usize, j: usize| {
+                let pq = stack![Axis(0), p.row(i), p.row(j)];
+                jensen_shannon_distance(&pq, None)
+            };
+
+            // Measures: d(P,R) <= d(P,Q) + d(Q,R)
+            assert!(pair(0, 2) <= pair(0, 1) + pair(1, 2) + EPSILON);
+            assert_relative_eq!(pair(0, 1), pair(1, 0), epsilon = EPSILON);
+            assert_eq!(pair(0, 0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_jsd_weight_normalization() {
+        let disjoint = array![[1.0, 0.0], [0.0, 1.0]];
+        let jsd = jensen_shannon_divergence(&disjoint, Some(&array![1.0, 1.0]));
+        assert_relative_eq!(jsd, LN_2, epsilon = EPSILON);
+
+        let p = array![[0.5, 0.5], [0.9, 0.1]];
+        let scaled = jensen_shannon_divergence(&p, Some(&array![2.0, 2.0]));
+        assert_relative_eq!(
+            scaled,
+            jensen_shannon_divergence(&p, None),
+            epsilon = EPSILON
+        );
+        assert!(scaled > 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jsd_negative_weight() {
+        let p = array![[0.5, 0.5], [0.9, 0.1]];
+        let _ = jensen_shannon_divergence(&p, Some(&array![1.5, -0.5]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jsd_non_finite_weight() {
+        let p = array![[0.5, 0.5], [0.9, 0.1]];
+        let _ = jensen_shannon_divergence(&p, Some(&array![f64::NAN, 1.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jsd_zero_weights() {
+        let p = array![[0.5, 0.5], [0.9, 0.1]];
+        let _ = jensen_shannon_divergence(&p, Some(&array![0.0, 0.0]));
+    }
 }
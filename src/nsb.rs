@@ -0,0 +1,229 @@
+use crate::special::{digamma, ln_gamma, trigamma};
+use anyhow::{bail, Result};
+use ndarray::Array1;
+use std::collections::BTreeMap;
+
+/// Number of points used to locate the posterior peak over the concentration parameter
+const COARSE_POINTS: usize = 400;
+
+/// Number of points used to integrate over the posterior
+const FINE_POINTS: usize = 2000;
+
+/// Log posterior weights below the peak by more than this are ignored
+const LOG_WEIGHT_CUTOFF: f64 = 50.0;
+
+/// Posterior mean and standard deviation of the entropy measured in nats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NsbEstimate {
+    pub mean: f64,
+    pub std: f64,
+}
+
+/// Histogram of the bin counts, including the empty bins of the alphabet
+struct CountsOfCounts {
+    /// pairs of (count, number of bins with that count)
+    multiplicities: Vec<(f64, f64)>,
+    nbins: f64,
+    total: f64,
+}
+
+impl CountsOfCounts {
+    fn new(counts: &Array1<usize>) -> Self {
+        let mut map = BTreeMap::new();
+        for c in counts.iter() {
+            *map.entry(*c).or_insert(0_usize) += 1;
+        }
+        Self {
+            multiplicities: map.into_iter().map(|(c, m)| (c as f64, m as f64)).collect(),
+            nbins: counts.len() as f64,
+            total: counts.sum() as f64,
+        }
+    }
+
+    /// Log evidence `ln[ p(n|β) ]` of the counts under a symmetric Dirichlet prior
+    fn log_evidence(&self, beta: f64) -> f64 {
+        let kb = self.nbins * beta;
+        self.multiplicities
+            .iter()
+            .filter(|(n, _)| *n > 0.0)
+            .fold(ln_gamma(kb) - ln_gamma(self.total + kb), |acc, (n, m)| {
+                acc + m * (ln_gamma(n + beta) - ln_gamma(beta))
+            })
+    }
+
+    /// Derivative of the prior expected entropy `ξ(β) = ψ(Kβ + 1) - ψ(β + 1)`
+    fn xi_derivative(&self, beta: f64) -> f64 {
+        self.nbins * trigamma(self.nbins * beta + 1.0) - trigamma(beta + 1.0)
+    }
+
+    /// First and second posterior moments of the entropy for a fixed `β`
+    fn moments(&self, beta: f64) -> (f64, f64) {
+        let a_total = self.total + self.nbins * beta;
+        let psi_a2 = digamma(a_total + 2.0);
+        let tri_a2 = trigamma(a_total + 2.0);
+
+        let mut first = digamma(a_total + 1.0);
+        let mut sum_u = 0.0;
+        let mut sum_u2 = 0.0;
+        let mut sum_a2 = 0.0;
+        let mut diagonal = 0.0;
+        for (n, m) in &self.multiplicities {
+            let a = n + beta;
+            first -= m * a / a_total * digamma(a + 1.0);
+            let u = a * (digamma(a + 1.0) - psi_a2);
+            sum_u += m * u;
+            sum_u2 += m * u * u;
+            sum_a2 += m * a * a;
+            diagonal += m
+                * a
+                * (a + 1.0)
+                * ((digamma(a + 2.0) - psi_a2).powi(2) + trigamma(a + 2.0) - tri_a2);
+        }
+        let off_diagonal = sum_u * sum_u - sum_u2 - tri_a2 * (a_total * a_total - sum_a2);
+        let second = (off_diagonal + diagonal) / (a_total * (a_total + 1.0));
+        (first, second)
+    }
+
+    /// Log posterior weight of `ln[ β ]` under the NSB prior which is uniform in `ξ`
+    fn log_weight(&self, log_beta: f64) -> f64 {
+        let beta = log_beta.exp();
+        self.log_evidence(beta) + (self.xi_derivative(beta) * beta).ln()
+    }
+}
+
+/// # NSB Entropy
+/// <https://arxiv.org/abs/physics/0108025>
+///
+/// Calculates the Nemenman–Shafee–Bialek Bayesian estimate of the entropy measured in nats from
+/// a histogram of event counts such as the one returned by [`hist1d`](crate::hist1d).
+///
+/// The counts are modelled with a symmetric Dirichlet prior whose concentration `β` is itself
+/// given a prior which is uniform over the prior expected entropy `ξ(β)`. The posterior mean and
+/// standard deviation of the entropy are then integrated over `β`:
+/// ```math
+/// E[H] ∝ ∫ dξ p(n|β(ξ)) E[H|n,β(ξ)]
+/// ```
+///
+/// The size of the alphabet is the length of the histogram, so empty bins must be included.
+/// Only the distinct count values are iterated over, which keeps alphabets where most bins are
+/// empty cheap.
+///
+/// # Errors
+/// If the histogram has fewer than two bins.
+///
+/// # Usage
+/// ```
+/// use ndarray::Array1;
+/// use ndarray_rand::{RandomExt, rand_distr::Uniform};
+/// use information::{entropy, hist1d, nsb_entropy, prob1d};
+///
+/// // heavily undersampled alphabet of 1000 equiprobable symbols
+/// let x = Array1::random(100, Uniform::new(0, 1000));
+/// let c_x = hist1d(&x, 1000).unwrap();
+/// let nsb = nsb_entropy(&c_x).unwrap();
+///
+/// let h_true = 1000_f64.ln();
+/// let h_plugin = entropy(&prob1d(&x, 1000).unwrap());
+/// assert!((nsb.mean - h_true).abs() < (h_plugin - h_true).abs());
+/// assert!(nsb.std > 0.0);
+/// ```
+pub fn nsb_entropy(counts: &Array1<usize>) -> Result<NsbEstimate> {
+    if counts.len() < 2 {
+        bail!("At least two bins are required to estimate the entropy");
+    }
+    let coc = CountsOfCounts::new(counts);
+
+    // locate the region of the posterior over ln[ β ] which carries the weight
+    let lower = (1e-8 / coc.nbins).ln();
+    let upper = 1e6_f64.ln();
+    let coarse_step = (upper - lower) / (COARSE_POINTS - 1) as f64;
+    let coarse: Vec<f64> = (0..COARSE_POINTS)
+        .map(|idx| coc.log_weight(lower + idx as f64 * coarse_step))
+        .collect();
+    let peak = coarse.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let first = coarse
+        .iter()
+        .position(|w| *w > peak - LOG_WEIGHT_CUTOFF)
+        .unwrap_or(0);
+    let last = coarse
+        .iter()
+        .rposition(|w| *w > peak - LOG_WEIGHT_CUTOFF)
+        .unwrap_or(COARSE_POINTS - 1);
+    let start = lower + first.saturating_sub(1) as f64 * coarse_step;
+    let end = lower + (last + 1).min(COARSE_POINTS - 1) as f64 * coarse_step;
+
+    // integrate the posterior moments with the trapezoidal rule
+    let step = (end - start) / (FINE_POINTS - 1) as f64;
+    let log_weights: Vec<f64> = (0..FINE_POINTS)
+        .map(|idx| coc.log_weight(start + idx as f64 * step))
+        .collect();
+    let peak = log_weights
+        .iter()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (mut norm, mut moment1, mut moment2) = (0.0, 0.0, 0.0);
+    for (idx, log_weight) in log_weights.iter().enumerate() {
+        let trapezoid = if idx == 0 || idx == FINE_POINTS - 1 {
+            0.5
+        } else {
+            1.0
+        };
+        let weight = trapezoid * (log_weight - peak).exp();
+        let (m1, m2) = coc.moments((start + idx as f64 * step).exp());
+        norm += weight;
+        moment1 += weight * m1;
+        moment2 += weight * m2;
+    }
+    let mean = moment1 / norm;
+    let std = (moment2 / norm - mean * mean).max(0.0).sqrt();
+    Ok(NsbEstimate { mean, std })
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::nsb_entropy;
+    use crate::{entropy::entropy, hist::hist1d, prob::prob1d};
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    const N_ITER: usize = 20;
+
+    #[test]
+    #[should_panic]
+    fn test_single_bin() {
+        nsb_entropy(&array![10]).unwrap();
+    }
+
+    #[test]
+    fn test_no_samples() {
+        // the posterior is the prior which is uniform in [0, ln K]
+        let nbins = 50_f64;
+        let nsb = nsb_entropy(&Array1::zeros(50)).unwrap();
+        assert_relative_eq!(nsb.mean, nbins.ln() / 2.0, epsilon = 1e-2);
+        assert!(nsb.std > 0.0);
+    }
+
+    #[test]
+    fn test_well_sampled() {
+        let x = Array1::random(100_000, Uniform::new(0, 4));
+        let nsb = nsb_entropy(&hist1d(&x, 4).unwrap()).unwrap();
+        let h_plugin = entropy(&prob1d(&x, 4).unwrap());
+        assert_relative_eq!(nsb.mean, h_plugin, epsilon = 1e-3);
+        assert!(nsb.std < 1e-2);
+    }
+
+    #[test]
+    fn test_undersampled() {
+        let nbins = 10_000;
+        let h_true = (nbins as f64).ln();
+        for _ in 0..N_ITER {
+            let x = Array1::random(500, Uniform::new(0, nbins));
+            let nsb = nsb_entropy(&hist1d(&x, nbins).unwrap()).unwrap();
+            let h_plugin = entropy(&prob1d(&x, nbins).unwrap());
+            assert!((nsb.mean - h_true).abs() < (h_plugin - h_true).abs());
+            assert!(nsb.mean <= h_true + 3.0 * nsb.std);
+        }
+    }
+}
@@ -0,0 +1,696 @@
+use crate::mutual::mutual_information;
+use anyhow::{bail, Result};
+use ndarray::{Array1, Array2, Array3, ArrayView1, ArrayViewMut1, Axis, Zip};
+
+/// Maximum number of scaling sweeps of each iterative proportional fitting projection
+const IPF_MAX_ITER: usize = 1000;
+
+/// Partial information decomposition of the information two sources carry about a target,
+/// measured in nats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pid {
+    /// Information both sources carry about the target
+    pub redundant: f64,
+    /// Information only the first source carries about the target
+    pub unique_x: f64,
+    /// Information only the second source carries about the target
+    pub unique_y: f64,
+    /// Information only both sources together carry about the target
+    pub synergistic: f64,
+}
+
+impl Pid {
+    /// Builds the decomposition from the redundancy and the mutual information between the
+    /// target and each source and both sources jointly
+    fn from_redundancy(redundant: f64, mi_x: f64, mi_y: f64, mi_xy: f64) -> Self {
+        Self {
+            redundant,
+            unique_x: mi_x - redundant,
+            unique_y: mi_y - redundant,
+            synergistic: mi_xy - mi_x - mi_y + redundant,
+        }
+    }
+
+    /// Sum of the four atoms, which is the mutual information `I(X,Y;T)`
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.redundant + self.unique_x + self.unique_y + self.synergistic
+    }
+}
+
+/// Specific information `I(T=t; S)` a source carries about each target value, from the joint
+/// probability matrix of the source (rows) and the target (columns)
+fn specific_information(p_st: &Array2<f64>) -> Array1<f64> {
+    let p_s = p_st.sum_axis(Axis(1));
+    let p_t = p_st.sum_axis(Axis(0));
+    Array1::from_shape_fn(p_t.len(), |t| {
+        if p_t[t] == 0.0 {
+            return 0.0;
+        }
+        p_st.column(t)
+            .iter()
+            .zip(p_s.iter())
+            .filter(|(st, _)| **st > 0.0)
+            .map(|(st, s)| st / p_t[t] * (st / (s * p_t[t])).ln())
+            .sum()
+    })
+}
+
+/// Mutual information `I(X,Y;T)` between both sources jointly and the target on the last axis
+fn joint_source_information(p_xyt: &Array3<f64>) -> f64 {
+    let (nx, ny, nt) = p_xyt.dim();
+    let p_xy_t = Array2::from_shape_vec((nx * ny, nt), p_xyt.iter().copied().collect())
+        .expect("the flattened cube has nx * ny * nt elements");
+    mutual_information(&p_xy_t)
+}
+
+/// Mutual information between each source and the target and between both sources jointly and
+/// the target, with the target on the last axis of the cube
+fn source_informations(p_xyt: &Array3<f64>) -> (f64, f64, f64) {
+    (
+        mutual_information(&p_xyt.sum_axis(Axis(1))),
+        mutual_information(&p_xyt.sum_axis(Axis(0))),
+        joint_source_information(p_xyt),
+    )
+}
+
+/// # Williams–Beer Partial Information Decomposition
+/// <https://arxiv.org/abs/1004.2515>
+///
+/// Decomposes the information two sources `X` and `Y` carry about a target `T` into redundant,
+/// unique and synergistic parts measured in nats, from their joint probability cube in the same
+/// layout [`conditional_mutual_information()`](crate::conditional_mutual_information()) takes
+/// with the target on the last axis.
+///
+/// The redundancy is the `I_min` measure of Williams and Beer (2010), the expected value over
+/// the target of the smallest specific information either source carries about it:
+/// ```math
+/// I(T=t; S) = Σ_s p(s|t) ln[ p(t|s) / p(t) ]
+/// I_min(T; X, Y) = Σ_t p(t) min[ I(T=t; X), I(T=t; Y) ]
+/// ```
+///
+/// The remaining atoms follow from the mutual informations:
+/// ```math
+/// U(X) = I(X;T) - I_min
+/// U(Y) = I(Y;T) - I_min
+/// S = I(X,Y;T) - I(X;T) - I(Y;T) + I_min
+/// ```
+///
+/// Every atom is non-negative and they add up to `I(X,Y;T)`. Since `I_min` only compares how
+/// much information each source carries about every target value and not which, two
+/// independent sources copied into the target are counted as redundant.
+///
+/// # Usage
+/// ```
+/// use ndarray::Array3;
+/// use information::williams_beer_pid;
+///
+/// // T is the exclusive or of two fair coins X and Y
+/// let mut p_xyt = Array3::zeros((2, 2, 2));
+/// for x in 0..2 {
+///     for y in 0..2 {
+///         p_xyt[[x, y, x ^ y]] = 0.25;
+///     }
+/// }
+///
+/// let pid = williams_beer_pid(&p_xyt);
+/// assert!(pid.redundant.abs() < 1e-12);
+/// assert!((pid.synergistic - 2.0_f64.ln()).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn williams_beer_pid(p_xyt: &Array3<f64>) -> Pid {
+    let p_t = p_xyt.sum_axis(Axis(0)).sum_axis(Axis(0));
+    let spec_x = specific_information(&p_xyt.sum_axis(Axis(1)));
+    let spec_y = specific_information(&p_xyt.sum_axis(Axis(0)));
+    let redundant = p_t
+        .iter()
+        .zip(spec_x.iter().zip(spec_y.iter()))
+        .map(|(t, (sx, sy))| t * sx.min(*sy))
+        .sum();
+    let (mi_x, mi_y, mi_xy) = source_informations(p_xyt);
+    Pid::from_redundancy(redundant, mi_x, mi_y, mi_xy)
+}
+
+/// Stopping rules of the optimization in [`broja_pid()`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrojaOptions {
+    /// Maximum number of alternating minimization steps
+    pub max_iter: usize,
+    /// Change of the objective between two steps below which the optimization has converged
+    pub tol: f64,
+    /// Largest deviation from the provided marginals accepted from each projection and from the
+    /// optimum
+    pub marginal_tol: f64,
+}
+
+impl Default for BrojaOptions {
+    fn default() -> Self {
+        Self {
+            max_iter: 100_000,
+            tol: 1e-9,
+            marginal_tol: 1e-10,
+        }
+    }
+}
+
+/// Convergence diagnostics of the optimization in [`broja_pid()`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrojaDiagnostics {
+    /// Number of alternating minimization steps taken
+    pub iterations: usize,
+    /// Whether the objective changed by less than [`BrojaOptions::tol`] before the iteration
+    /// limit with the marginals of the optimum within [`BrojaOptions::marginal_tol`] of the
+    /// provided ones
+    pub converged: bool,
+    /// Minimized `I(X,Y;T)` measured in nats
+    pub objective: f64,
+    /// Change of the objective in the last step
+    pub objective_change: f64,
+    /// Largest absolute deviation of the `(X,T)` and `(Y,T)` marginals of the optimum from
+    /// those of the provided distribution
+    pub marginal_error: f64,
+    /// Number of projections of a target slice that stopped at the sweep limit before their
+    /// marginals were within [`BrojaOptions::marginal_tol`]
+    pub capped_projections: usize,
+}
+
+/// Largest absolute deviation of the source-target marginals of `q` from `p_xt` and `p_yt`,
+/// which is NaN if any deviation is
+fn marginal_error(q: &Array3<f64>, p_xt: &Array2<f64>, p_yt: &Array2<f64>) -> f64 {
+    let q_xt = q.sum_axis(Axis(1));
+    let q_yt = q.sum_axis(Axis(0));
+    q_xt.iter()
+        .zip(p_xt.iter())
+        .chain(q_yt.iter().zip(p_yt.iter()))
+        .map(|(a, b)| (a - b).abs())
+        .fold(0.0, |max, e| if e.is_nan() || e > max { e } else { max })
+}
+
+/// I-projection `a(x) r(x,y) b(y)` of `r` onto the matrices with row sums `rows` and column sums
+/// `cols` by iterative proportional fitting of the scaling factors `a` and `b`
+///
+/// The factors are updated in place so that they warm start the next projection. Also returns
+/// whether the row sums reached the tolerance within [`IPF_MAX_ITER`] sweeps.
+fn ipf(
+    r: &Array2<f64>,
+    mut a: ArrayViewMut1<f64>,
+    mut b: ArrayViewMut1<f64>,
+    rows: ArrayView1<f64>,
+    cols: ArrayView1<f64>,
+    tol: f64,
+) -> (Array2<f64>, bool) {
+    let scale = |factor: &mut f64, target: f64, sum: f64| {
+        if sum > 0.0 {
+            *factor = target / sum;
+        }
+    };
+    let mut fitted = false;
+    for _ in 0..IPF_MAX_ITER {
+        for (x, factor) in a.iter_mut().enumerate() {
+            scale(factor, rows[x], r.row(x).dot(&b));
+        }
+        for (y, factor) in b.iter_mut().enumerate() {
+            scale(factor, cols[y], r.column(y).dot(&a));
+        }
+        // the columns are exact after their scaling so only the rows can be off, and diverging
+        // factors of an infeasible projection leave NaN sums that never count as fitted
+        if (0..rows.len()).all(|x| (a[x] * r.row(x).dot(&b) - rows[x]).abs() < tol) {
+            fitted = true;
+            break;
+        }
+    }
+    let projected = Array2::from_shape_fn(r.raw_dim(), |(x, y)| a[x] * r[[x, y]] * b[y]);
+    (projected, fitted)
+}
+
+/// # BROJA Partial Information Decomposition
+/// <https://doi.org/10.3390/e16042161>
+///
+/// Decomposes the information two sources `X` and `Y` carry about a target `T` into redundant,
+/// unique and synergistic parts measured in nats following Bertschinger, Rauh, Olbrich, Jost and
+/// Ay (2014), from their joint probability cube in the same layout as [`williams_beer_pid()`]
+/// with the target on the last axis.
+///
+/// The unique information only depends on the pairwise `(X,T)` and `(Y,T)` marginals, so it is
+/// the smallest conditional mutual information over the set `Δ` of distributions `Q` sharing
+/// them with the provided distribution `P`:
+/// ```math
+/// U(X) = min_{Q ∈ Δ} I_Q(T;X|Y)
+/// U(Y) = min_{Q ∈ Δ} I_Q(T;Y|X)
+/// ```
+///
+/// Both are attained by the `Q` minimizing `I_Q(X,Y;T)`. It is found by alternating
+/// minimization of the divergence between `Q(X,Y,T)` and `R(X,Y) P(T)`, where the optimal `R` is
+/// the `(X,Y)` marginal of `Q` and the optimal `Q` is the I-projection of `R` onto `Δ`, computed
+/// by iterative proportional fitting of each target slice warm started from the scaling factors
+/// of the previous step. Every step decreases the objective, and since it is convex the
+/// iteration converges to the global minimum. The remaining atoms then follow:
+/// ```math
+/// R = I(X;T) + I(Y;T) - I_Q(X,Y;T)
+/// S = I_P(X,Y;T) - I_Q(X,Y;T)
+/// ```
+///
+/// Unlike [`williams_beer_pid()`], two independent sources copied into the target carry only
+/// unique information.
+///
+/// Alternating minimization converges slowly when the optimum lies on the boundary of `Δ`, so
+/// the returned [`BrojaDiagnostics`] report whether the objective settled within
+/// [`BrojaOptions::max_iter`] steps, how far the optimum is from the provided marginals and how
+/// many projections failed to fit them. Since the steps shrink long before the objective reaches
+/// its minimum, the default [`BrojaOptions::tol`] on the objective is looser than the
+/// [`BrojaOptions::marginal_tol`] each projection is fitted to, and the default
+/// [`BrojaOptions::max_iter`] lets random cubes of up to five states per variable converge.
+///
+/// # Errors
+/// If the probabilities are negative, not finite or all zero, if `max_iter` is zero, or if `tol`
+/// or `marginal_tol` is not positive and finite.
+///
+/// # Usage
+/// ```
+/// use ndarray::Array3;
+/// use information::{broja_pid, BrojaOptions};
+///
+/// // T copies two independent fair coins X and Y
+/// let mut p_xyt = Array3::zeros((2, 2, 4));
+/// for x in 0..2 {
+///     for y in 0..2 {
+///         p_xyt[[x, y, 2 * x + y]] = 0.25;
+///     }
+/// }
+///
+/// let (pid, diagnostics) = broja_pid(&p_xyt, &BrojaOptions::default()).unwrap();
+/// assert!(diagnostics.converged);
+/// assert!(pid.redundant.abs() < 1e-8);
+/// assert!((pid.unique_x - 2.0_f64.ln()).abs() < 1e-8);
+/// assert!((pid.unique_y - 2.0_f64.ln()).abs() < 1e-8);
+/// ```
+pub fn broja_pid(p_xyt: &Array3<f64>, options: &BrojaOptions) -> Result<(Pid, BrojaDiagnostics)> {
+    if options.max_iter == 0 {
+        bail!("At least one iteration is required");
+    }
+    if !(options.tol > 0.0 && options.tol.is_finite()) {
+        bail!("Provided tolerance must be positive and finite");
+    }
+    if !(options.marginal_tol > 0.0 && options.marginal_tol.is_finite()) {
+        bail!("Provided marginal tolerance must be positive and finite");
+    }
+    if p_xyt.iter().any(|p| !(p.is_finite() && *p >= 0.0)) {
+        bail!("Provided probabilities must be non-negative and finite");
+    }
+    if p_xyt.sum() <= 0.0 {
+        bail!("Provided probabilities must not all be zero");
+    }
+
+    let p_xt = p_xyt.sum_axis(Axis(1));
+    let p_yt = p_xyt.sum_axis(Axis(0));
+    let p_t = p_xt.sum_axis(Axis(0));
+
+    // start from the sources being independent given the target, which lies in Δ
+    let mut q = Array3::zeros(p_xyt.raw_dim());
+    Zip::indexed(&mut q).for_each(|(x, y, t), q| {
+        if p_t[t] > 0.0 {
+            *q = p_xt[[x, t]] * p_yt[[y, t]] / p_t[t];
+        }
+    });
+
+    let mut a = Array2::ones(p_xt.raw_dim());
+    let mut b = Array2::ones(p_yt.raw_dim());
+    let mut objective = joint_source_information(&q);
+    let mut objective_change = f64::INFINITY;
+    let mut iterations = 0;
+    let mut capped_projections = 0;
+    while iterations < options.max_iter && objective_change >= options.tol {
+        let r = q.sum_axis(Axis(2));
+        for (t, mut slice) in q.axis_iter_mut(Axis(2)).enumerate() {
+            if p_t[t] > 0.0 {
+                let (projected, fitted) = ipf(
+                    &r,
+                    a.column_mut(t),
+                    b.column_mut(t),
+                    p_xt.column(t),
+                    p_yt.column(t),
+                    options.marginal_tol,
+                );
+                slice.assign(&projected);
+                capped_projections += usize::from(!fitted);
+            }
+        }
+        let next = joint_source_information(&q);
+        objective_change = (objective - next).abs();
+        objective = next;
+        iterations += 1;
+    }
+
+    let (mi_x, mi_y, mi_xy) = source_informations(p_xyt);
+    let pid = Pid::from_redundancy(mi_x + mi_y - objective, mi_x, mi_y, mi_xy);
+    let marginal_error = marginal_error(&q, &p_xt, &p_yt);
+    let diagnostics = BrojaDiagnostics {
+        iterations,
+        converged: objective_change < options.tol && marginal_error <= options.marginal_tol,
+        objective,
+        objective_change,
+        marginal_error,
+        capped_projections,
+    };
+    Ok((pid, diagnostics))
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{broja_pid, ipf, specific_information, williams_beer_pid, BrojaOptions, Pid};
+    use crate::{cmi::conditional_mutual_information, mutual::mutual_information};
+    use approx::assert_relative_eq;
+    use ndarray::{array, s, Array1, Array2, Array3, Axis};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+    use std::f64::consts::LN_2;
+
+    const N_ITER: usize = 1000;
+    const EPSILON: f64 = 1e-12;
+    const N_BROJA: usize = 20;
+    const BROJA_EPSILON: f64 = 1e-5;
+
+    /// Joint cube of two fair coins and a target computed from them
+    fn gate(target: impl Fn(usize, usize) -> usize, nt: usize) -> Array3<f64> {
+        let mut p_xyt = Array3::zeros((2, 2, nt));
+        for x in 0..2 {
+            for y in 0..2 {
+                p_xyt[[x, y, target(x, y)]] += 0.25;
+            }
+        }
+        p_xyt
+    }
+
+    fn assert_pid(pid: Pid, expected: [f64; 4]) {
+        assert_relative_eq!(pid.redundant, expected[0], epsilon = EPSILON);
+        assert_relative_eq!(pid.unique_x, expected[1], epsilon = EPSILON);
+        assert_relative_eq!(pid.unique_y, expected[2], epsilon = EPSILON);
+        assert_relative_eq!(pid.synergistic, expected[3], epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_specific_information() {
+        // the expected specific information is the mutual information
+        let c_st = Array2::random((4, 3), Uniform::new(0.0, 1.0));
+        let p_st = &c_st / c_st.sum();
+        let spec = specific_information(&p_st);
+        let expected = (&spec * &p_st.sum_axis(Axis(0))).sum();
+        assert_relative_eq!(expected, mutual_information(&p_st), epsilon = EPSILON);
+        assert!(spec.iter().all(|s| *s >= 0.0));
+    }
+
+    #[test]
+    fn test_xor() {
+        let pid = williams_beer_pid(&gate(|x, y| x ^ y, 2));
+        assert_pid(pid, [0.0, 0.0, 0.0, LN_2]);
+    }
+
+    #[test]
+    fn test_and() {
+        let p_xyt = gate(|x, y| x & y, 2);
+        let pid = williams_beer_pid(&p_xyt);
+        let mi_x = mutual_information(&p_xyt.sum_axis(Axis(1)));
+        assert_pid(pid, [mi_x, 0.0, 0.0, 0.5 * LN_2]);
+        assert_relative_eq!(mi_x, 0.311_278_124_459_132_8 * LN_2, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_copy() {
+        // I_min counts the two independent copied bits as redundant
+        let pid = williams_beer_pid(&gate(|x, y| 2 * x + y, 4));
+        assert_pid(pid, [LN_2, 0.0, 0.0, LN_2]);
+        assert_relative_eq!(pid.total(), 2.0 * LN_2, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn test_rdn() {
+        let mut p_xyt = Array3::zeros((2, 2, 2));
+        p_xyt[[0, 0, 0]] = 0.5;
+        p_xyt[[1, 1, 1]] = 0.5;
+        assert_pid(williams_beer_pid(&p_xyt), [LN_2, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_unq() {
+        let pid = williams_beer_pid(&gate(|x, _| x, 2));
+        assert_pid(pid, [0.0, LN_2, 0.0, 0.0]);
+    }
+
+    #[test]
+    /// Measures: every atom is non-negative and the redundancy matches the definition of I_min
+    fn test_consistency() {
+        for _ in 0..N_ITER {
+            let c_xyt = Array3::random((3, 2, 4), Uniform::new(0.0, 1.0));
+            let p_xyt = &c_xyt / c_xyt.sum();
+            let pid = williams_beer_pid(&p_xyt);
+            assert!(pid.redundant >= -EPSILON);
+            assert!(pid.unique_x >= -EPSILON);
+            assert!(pid.unique_y >= -EPSILON);
+            assert!(pid.synergistic >= -EPSILON);
+
+            // I(T=t; S) = Σ_s p(s|t) ln[ p(t|s) / p(t) ] from the full cube
+            let p_t = p_xyt.sum_axis(Axis(0)).sum_axis(Axis(0));
+            let specific = |axis: usize, t: usize| {
+                let p_st = p_xyt.sum_axis(Axis(1 - axis));
+                (0..p_st.nrows())
+                    .map(|src| {
+                        let p_s = p_st.row(src).sum();
+                        p_st[[src, t]] / p_t[t] * (p_st[[src, t]] / p_s / p_t[t]).ln()
+                    })
+                    .sum::<f64>()
+            };
+            let i_min = (0..p_t.len())
+                .map(|t| p_t[t] * specific(0, t).min(specific(1, t)))
+                .sum::<f64>();
+            assert_relative_eq!(pid.redundant, i_min, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    /// Measures: swapping the sources swaps their unique information
+    fn test_source_symmetry() {
+        for _ in 0..N_ITER {
+            let c_xyt = Array3::random((3, 2, 4), Uniform::new(0.0, 1.0));
+            let p_xyt = &c_xyt / c_xyt.sum();
+            let p_yxt = p_xyt.view().permuted_axes([1, 0, 2]).to_owned();
+            let pid = williams_beer_pid(&p_xyt);
+            let swapped = williams_beer_pid(&p_yxt);
+            assert_relative_eq!(pid.redundant, swapped.redundant, epsilon = EPSILON);
+            assert_relative_eq!(pid.unique_x, swapped.unique_y, epsilon = EPSILON);
+            assert_relative_eq!(pid.unique_y, swapped.unique_x, epsilon = EPSILON);
+            assert_relative_eq!(pid.synergistic, swapped.synergistic, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    /// Measures: two copies of the same source carry only redundant information
+    fn test_identical_sources() {
+        for _ in 0..N_ITER {
+            let c_xt = Array2::random((3, 4), Uniform::new(0.0, 1.0));
+            let p_xt = &c_xt / c_xt.sum();
+            let mut p_xxt = Array3::zeros((3, 3, 4));
+            for x in 0..3 {
+                p_xxt.slice_mut(s![x, x, ..]).assign(&p_xt.row(x));
+            }
+            let pid = williams_beer_pid(&p_xxt);
+            assert_pid(pid, [mutual_information(&p_xt), 0.0, 0.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn test_zeros() {
+        assert_pid(williams_beer_pid(&Array3::zeros((2, 3, 4))), [0.0; 4]);
+    }
+
+    fn broja(p_xyt: &Array3<f64>) -> Pid {
+        let (pid, diagnostics) = broja_pid(p_xyt, &BrojaOptions::default()).unwrap();
+        assert!(diagnostics.converged);
+        assert!(diagnostics.marginal_error < 1e-9);
+        pid
+    }
+
+    fn assert_pid_close(pid: Pid, expected: [f64; 4]) {
+        assert_relative_eq!(pid.redundant, expected[0], epsilon = BROJA_EPSILON);
+        assert_relative_eq!(pid.unique_x, expected[1], epsilon = BROJA_EPSILON);
+        assert_relative_eq!(pid.unique_y, expected[2], epsilon = BROJA_EPSILON);
+        assert_relative_eq!(pid.synergistic, expected[3], epsilon = BROJA_EPSILON);
+    }
+
+    #[test]
+    fn test_broja_gates() {
+        assert_pid_close(broja(&gate(|x, y| x ^ y, 2)), [0.0, 0.0, 0.0, LN_2]);
+        assert_pid_close(broja(&gate(|x, y| 2 * x + y, 4)), [0.0, LN_2, LN_2, 0.0]);
+        assert_pid_close(broja(&gate(|x, _| x, 2)), [0.0, LN_2, 0.0, 0.0]);
+
+        let p_and = gate(|x, y| x & y, 2);
+        let mi_x = mutual_information(&p_and.sum_axis(Axis(1)));
+        assert_pid_close(broja(&p_and), [mi_x, 0.0, 0.0, 0.5 * LN_2]);
+
+        let mut p_rdn = Array3::zeros((2, 2, 2));
+        p_rdn[[0, 0, 0]] = 0.5;
+        p_rdn[[1, 1, 1]] = 0.5;
+        assert_pid_close(broja(&p_rdn), [LN_2, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    /// Measures: the atoms are non-negative and the optimum matches the source-target marginals
+    fn test_broja_consistency() {
+        for _ in 0..N_BROJA {
+            let c_xyt = Array3::random((3, 2, 3), Uniform::new(0.0, 1.0));
+            let p_xyt = &c_xyt / c_xyt.sum();
+            let (pid, diagnostics) = broja_pid(&p_xyt, &BrojaOptions::default()).unwrap();
+            assert!(diagnostics.converged);
+            assert!(diagnostics.marginal_error <= 1e-10);
+            assert!(pid.redundant >= -BROJA_EPSILON);
+            assert!(pid.unique_x >= -BROJA_EPSILON);
+            assert!(pid.unique_y >= -BROJA_EPSILON);
+            assert!(pid.synergistic >= -BROJA_EPSILON);
+
+            // the unique information can not exceed that of any other distribution in Δ,
+            // including the provided one
+            let i_x_given_y =
+                conditional_mutual_information(&p_xyt.view().permuted_axes([0, 2, 1]).to_owned());
+            assert!(pid.unique_x <= i_x_given_y + BROJA_EPSILON);
+        }
+    }
+
+    #[test]
+    /// Measures: the unique information is the minimum of I_Q(T;X|Y) over Δ, found here by a grid
+    /// search over the single free probability of each 2 x 2 target slice
+    fn test_broja_grid_search() {
+        const STEPS: usize = 100;
+        for _ in 0..3 {
+            let c_xyt: Array3<f64> = Array3::random((2, 2, 2), Uniform::new(0.0, 1.0));
+            let p_xyt = &c_xyt / c_xyt.sum();
+            let p_xt = p_xyt.sum_axis(Axis(1));
+            let p_yt = p_xyt.sum_axis(Axis(0));
+            let p_t = p_xt.sum_axis(Axis(0));
+
+            // q(0,0,t) ranges over the values keeping every cell of the slice non-negative
+            let bounds: Vec<(f64, f64)> = (0..2)
+                .map(|t| {
+                    let lo = (p_xt[[0, t]] + p_yt[[0, t]] - p_t[t]).max(0.0);
+                    (lo, p_xt[[0, t]].min(p_yt[[0, t]]))
+                })
+                .collect();
+            let point = |t: usize, i: usize| {
+                let (lo, hi) = bounds[t];
+                lo + (hi - lo) * i as f64 / STEPS as f64
+            };
+            let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+            for i in 0..=STEPS {
+                for j in 0..=STEPS {
+                    let mut q = Array3::zeros((2, 2, 2));
+                    for (t, k) in [(0, i), (1, j)] {
+                        let q00 = point(t, k);
+                        q[[0, 0, t]] = q00;
+                        q[[0, 1, t]] = p_xt[[0, t]] - q00;
+                        q[[1, 0, t]] = p_yt[[0, t]] - q00;
+                        q[[1, 1, t]] = (p_t[t] - p_xt[[0, t]] - p_yt[[0, t]] + q00).max(0.0);
+                    }
+                    let i_x = conditional_mutual_information(
+                        &q.view().permuted_axes([0, 2, 1]).to_owned(),
+                    );
+                    let i_y = conditional_mutual_information(
+                        &q.view().permuted_axes([1, 2, 0]).to_owned(),
+                    );
+                    min_x = min_x.min(i_x);
+                    min_y = min_y.min(i_y);
+                }
+            }
+
+            let (pid, _) = broja_pid(&p_xyt, &BrojaOptions::default()).unwrap();
+            assert!(pid.unique_x <= min_x + BROJA_EPSILON);
+            assert!(pid.unique_y <= min_y + BROJA_EPSILON);
+            assert_relative_eq!(pid.unique_x, min_x, epsilon = 1e-3);
+            assert_relative_eq!(pid.unique_y, min_y, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_broja_converged() {
+        // the default options settle random cubes of a few states per variable
+        for shape in [(4, 4, 3), (5, 5, 5)] {
+            let c_xyt = Array3::random(shape, Uniform::new(0.0, 1.0));
+            let p_xyt = &c_xyt / c_xyt.sum();
+            let (_, diagnostics) = broja_pid(&p_xyt, &BrojaOptions::default()).unwrap();
+            assert!(diagnostics.converged);
+            assert_eq!(diagnostics.capped_projections, 0);
+        }
+    }
+
+    #[test]
+    fn test_broja_diagnostics() {
+        let c_xyt = Array3::random((4, 4, 3), Uniform::new(0.0, 1.0));
+        let p_xyt = &c_xyt / c_xyt.sum();
+        let options = BrojaOptions {
+            max_iter: 1,
+            ..BrojaOptions::default()
+        };
+        let (_, diagnostics) = broja_pid(&p_xyt, &options).unwrap();
+        assert_eq!(diagnostics.iterations, 1);
+        assert!(!diagnostics.converged);
+
+        let (_, diagnostics) = broja_pid(&p_xyt, &BrojaOptions::default()).unwrap();
+        assert_eq!(diagnostics.capped_projections, 0);
+    }
+
+    #[test]
+    fn test_ipf_cap() {
+        // a diagonal matrix can not be scaled to unequal row and column sums
+        let r = array![[0.5, 0.0], [0.0, 0.5]];
+        let (mut a, mut b) = (Array1::ones(2), Array1::ones(2));
+        let rows = array![0.5, 0.5];
+        let cols = array![0.9, 0.1];
+        let (_, fitted) = ipf(
+            &r,
+            a.view_mut(),
+            b.view_mut(),
+            rows.view(),
+            cols.view(),
+            1e-10,
+        );
+        assert!(!fitted);
+    }
+
+    #[test]
+    fn test_broja_cap() {
+        // projections can not fit the marginals to a tolerance below the rounding error, so
+        // they stop at the sweep limit and the optimization is not converged
+        let c_xyt = Array3::from_shape_fn((3, 3, 2), |(x, y, t)| {
+            1.0 + ((7 * x + 5 * y + 3 * t) % 11) as f64 / 7.0
+        });
+        let p_xyt = &c_xyt / c_xyt.sum();
+        let options = BrojaOptions {
+            max_iter: 2,
+            marginal_tol: 1e-300,
+            ..BrojaOptions::default()
+        };
+        let (_, diagnostics) = broja_pid(&p_xyt, &options).unwrap();
+        assert!(diagnostics.capped_projections > 0);
+        assert!(!diagnostics.converged);
+    }
+
+    #[test]
+    fn test_broja_invalid() {
+        let p_xyt = gate(|x, y| x ^ y, 2);
+        let options = BrojaOptions::default();
+        let no_iter = BrojaOptions {
+            max_iter: 0,
+            ..options
+        };
+        let no_tol = BrojaOptions {
+            tol: 0.0,
+            ..options
+        };
+        assert!(broja_pid(&p_xyt, &no_iter).is_err());
+        let no_marginal_tol = BrojaOptions {
+            marginal_tol: f64::NAN,
+            ..options
+        };
+        assert!(broja_pid(&p_xyt, &no_tol).is_err());
+        assert!(broja_pid(&p_xyt, &no_marginal_tol).is_err());
+        assert!(broja_pid(&-&p_xyt, &options).is_err());
+        assert!(broja_pid(&Array3::zeros((2, 2, 2)), &options).is_err());
+    }
+}
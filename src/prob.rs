@@ -1,6 +1,6 @@
-use crate::hist::{hist1d, hist2d, hist3d};
+use crate::hist::{hist1d, hist2d, hist3d, histnd};
 use anyhow::Result;
-use ndarray::{Array1, Array2, Array3};
+use ndarray::{Array1, Array2, Array3, ArrayD};
 
 /// Calculates the probability of events in each bin for a single integer array
 ///
@@ -76,9 +76,33 @@ pub fn prob3d(
     Ok(hist / total)
 }
 
+/// Calculates the event intersection probability between an arbitrary number of arrays of equal size
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{joint_entropy, probnd};
+///
+/// let arr_a = array![0, 1, 0, 1];
+/// let arr_b = array![0, 1, 0, 1];
+/// let arr_c = array![0, 1, 1, 0];
+/// let arr_d = array![1, 1, 1, 1];
+/// let prob = probnd(&[arr_a, arr_b, arr_c, arr_d], &[2, 2, 2, 2]).unwrap();
+/// assert_eq!(prob.shape(), &[2, 2, 2, 2]);
+/// assert_eq!(prob[[0, 0, 0, 1]], 0.25);
+///
+/// let h = joint_entropy!(&prob);
+/// assert_eq!(h, 4.0 * -(0.25_f64 * 0.25_f64.ln()));
+/// ```
+pub fn probnd(arrs: &[Array1<usize>], nbins: &[usize]) -> Result<ArrayD<f64>> {
+    let hist = histnd(arrs, nbins)?.mapv(|x| x as f64);
+    let total = hist.sum();
+    Ok(hist / total)
+}
+
 #[cfg(test)]
 mod testing {
-    use super::{prob1d, prob2d, prob3d};
+    use super::{prob1d, prob2d, prob3d, probnd};
     use ndarray::{array, Array1};
 
     #[test]
@@ -128,4 +152,15 @@ mod testing {
         assert_eq!(prob.shape(), &[2, 2, 2]);
         assert_eq!(prob, expected);
     }
+
+    #[test]
+    fn test_nd_basic() {
+        let arr_a = array![0, 1];
+        let arr_b = array![0, 1];
+        let arr_c = array![0, 1];
+        let expected = prob3d(&arr_a, &arr_b, &arr_c, 2, 2, 2).unwrap();
+        let prob = probnd(&[arr_a, arr_b, arr_c], &[2, 2, 2]).unwrap();
+        assert_eq!(prob.shape(), &[2, 2, 2]);
+        assert_eq!(prob, expected.into_dyn());
+    }
 }
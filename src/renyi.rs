@@ -0,0 +1,271 @@
+use crate::{divergence::kl_divergence, joint_entropy};
+use ndarray::{Array, Array2, Axis, Dimension, Zip};
+
+/// # Rényi Entropy
+/// <https://en.wikipedia.org/wiki/R%C3%A9nyi_entropy>
+///
+/// Calculates the Rényi entropy of order `α` of a probability array of any dimension measured in
+/// nats.
+///
+/// ```math
+/// Hα(X) = 1 / (1 - α) * ln[ Σ p(x)^α ]
+/// ```
+///
+/// The limiting cases are handled explicitly:
+/// * `α = 0`: Hartley entropy `ln[ |supp(p)| ]`
+/// * `α = 1`: Shannon entropy, matching [`entropy()`](crate::entropy())
+/// * `α = 2`: collision entropy `-ln[ Σ p(x)² ]`
+/// * `α = ∞`: min-entropy `-ln[ max p(x) ]`
+///
+/// # Panics
+/// If `α` is negative or NaN.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{entropy, renyi_entropy};
+///
+/// let p = array![0.5, 0.25, 0.25, 0.0];
+///
+/// assert_eq!(renyi_entropy(&p, 0.0), 3.0_f64.ln());
+/// assert_eq!(renyi_entropy(&p, 1.0), entropy(&p));
+/// assert_eq!(renyi_entropy(&p, 2.0), -(0.375_f64).ln());
+/// assert_eq!(renyi_entropy(&p, f64::INFINITY), 2.0_f64.ln());
+/// ```
+#[must_use]
+pub fn renyi_entropy<D: Dimension>(p: &Array<f64, D>, alpha: f64) -> f64 {
+    assert!(alpha >= 0.0, "The order must be non-negative");
+    let support = p.iter().filter(|x| **x > 0.0);
+    if alpha == 0.0 {
+        (support.count() as f64).ln()
+    } else if alpha == 1.0 {
+        joint_entropy!(p)
+    } else if alpha == f64::INFINITY {
+        -support.fold(0.0, |acc: f64, x| acc.max(*x)).ln()
+    } else {
+        support.map(|x| x.powf(alpha)).sum::<f64>().ln() / (1.0 - alpha)
+    }
+}
+
+/// # Tsallis Entropy
+/// <https://en.wikipedia.org/wiki/Tsallis_entropy>
+///
+/// Calculates the Tsallis entropy with entropic index `q` of a probability array of any
+/// dimension.
+///
+/// ```math
+/// Sq(X) = (1 - Σ p(x)^q) / (q - 1)
+/// ```
+///
+/// At `q = 1` this is the Shannon entropy in nats, matching [`entropy()`](crate::entropy()).
+///
+/// # Panics
+/// If `q` is not finite.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{entropy, tsallis_entropy};
+///
+/// let p = array![0.5, 0.25, 0.25];
+///
+/// assert_eq!(tsallis_entropy(&p, 1.0), entropy(&p));
+/// assert_eq!(tsallis_entropy(&p, 2.0), 1.0 - 0.375);
+/// ```
+#[must_use]
+pub fn tsallis_entropy<D: Dimension>(p: &Array<f64, D>, q: f64) -> f64 {
+    assert!(q.is_finite(), "The entropic index must be finite");
+    if q == 1.0 {
+        joint_entropy!(p)
+    } else {
+        let sum = p
+            .iter()
+            .filter(|x| **x > 0.0)
+            .map(|x| x.powf(q))
+            .sum::<f64>();
+        (1.0 - sum) / (q - 1.0)
+    }
+}
+
+/// # Rényi Divergence
+/// <https://en.wikipedia.org/wiki/R%C3%A9nyi_entropy#R%C3%A9nyi_divergence>
+///
+/// Calculates the Rényi divergence of order `α` of a probability array `P` from another
+/// probability array `Q` of the same shape measured in nats.
+///
+/// ```math
+/// Dα(P||Q) = 1 / (α - 1) * ln[ Σ p(x)^α * q(x)^(1 - α) ]
+/// ```
+///
+/// Terms where `p(x) = 0` are skipped. The limiting cases are handled explicitly:
+/// * `α = 0`: `-ln[ Σ q(x) ]` over the support of `P`
+/// * `α = 1`: the [`kl_divergence()`](crate::kl_divergence())
+/// * `α = ∞`: `ln[ max p(x) / q(x) ]`
+///
+/// # Panics
+/// If `α` is negative or NaN, or if the two arrays are not of equal shape.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{kl_divergence, renyi_divergence};
+///
+/// let p = array![0.5, 0.5];
+/// let q = array![0.25, 0.75];
+///
+/// assert_eq!(renyi_divergence(&p, &q, 1.0), kl_divergence(&p, &q));
+/// assert_eq!(renyi_divergence(&p, &q, f64::INFINITY), 2.0_f64.ln());
+/// assert!(renyi_divergence(&p, &q, 0.5) <= renyi_divergence(&p, &q, 2.0));
+/// ```
+#[must_use]
+pub fn renyi_divergence<D: Dimension>(p: &Array<f64, D>, q: &Array<f64, D>, alpha: f64) -> f64 {
+    assert!(alpha >= 0.0, "The order must be non-negative");
+    assert_eq!(
+        p.shape(),
+        q.shape(),
+        "Provided distributions must be of equal shape"
+    );
+    if alpha == 1.0 {
+        return kl_divergence(p, q);
+    }
+    let support = Zip::from(p).and(q).fold(Vec::new(), |mut acc, p, q| {
+        if *p > 0.0 {
+            acc.push((*p, *q));
+        }
+        acc
+    });
+    if alpha == 0.0 {
+        -support.iter().map(|(_, q)| q).sum::<f64>().ln()
+    } else if alpha == f64::INFINITY {
+        support.iter().map(|(p, q)| p / q).fold(0.0, f64::max).ln()
+    } else {
+        let sum = support
+            .iter()
+            .map(|(p, q)| p.powf(alpha) * q.powf(1.0 - alpha))
+            .sum::<f64>();
+        sum.ln() / (alpha - 1.0)
+    }
+}
+
+/// # Rényi Mutual Information
+/// Calculates the Rényi mutual information of order `α` of a two-dimensional probability matrix
+/// measured in nats.
+///
+/// This is the [`renyi_divergence()`] of the joint distribution from the product of its
+/// marginals:
+/// ```math
+/// Iα(X;Y) = Dα( p(x,y) || p(x) * p(y) )
+/// ```
+///
+/// At `α = 1` it matches [`mutual_information()`](crate::mutual_information()).
+///
+/// # Panics
+/// If `α` is negative or NaN.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{mutual_information, renyi_mutual_information};
+///
+/// let p_xy = array![[0.5, 0.0], [0.25, 0.25]];
+/// let i_1 = renyi_mutual_information(&p_xy, 1.0);
+/// assert!((i_1 - mutual_information(&p_xy)).abs() < 1e-12);
+///
+/// let i_2 = renyi_mutual_information(&p_xy, 2.0);
+/// assert!(i_2 >= i_1);
+/// ```
+#[must_use]
+pub fn renyi_mutual_information(p_xy: &Array2<f64>, alpha: f64) -> f64 {
+    let p_x = p_xy.sum_axis(Axis(1)).insert_axis(Axis(1));
+    let p_y = p_xy.sum_axis(Axis(0)).insert_axis(Axis(0));
+    renyi_divergence(p_xy, &(&p_x * &p_y), alpha)
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{renyi_divergence, renyi_entropy, renyi_mutual_information, tsallis_entropy};
+    use crate::{divergence::kl_divergence, entropy::entropy, mutual::mutual_information};
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1, Array2};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    const N_ITER: usize = 1000;
+    const ARRAY_SIZE: usize = 100;
+    const EPSILON: f64 = 1e-12;
+    const ORDERS: [f64; 7] = [0.0, 0.5, 1.0, 1.5, 2.0, 10.0, f64::INFINITY];
+
+    #[test]
+    fn test_uniform() {
+        // every order agrees on the uniform distribution
+        let p = Array1::from_elem(8, 0.125);
+        for alpha in ORDERS {
+            assert_relative_eq!(renyi_entropy(&p, alpha), 8_f64.ln(), epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_shannon_limit() {
+        let p = array![0.5, 0.3, 0.2, 0.0];
+        let h = entropy(&p);
+        assert_relative_eq!(renyi_entropy(&p, 1.0 - 1e-7), h, epsilon = 1e-6);
+        assert_relative_eq!(renyi_entropy(&p, 1.0 + 1e-7), h, epsilon = 1e-6);
+        assert_relative_eq!(tsallis_entropy(&p, 1.0 - 1e-7), h, epsilon = 1e-6);
+        assert_relative_eq!(tsallis_entropy(&p, 1.0 + 1e-7), h, epsilon = 1e-6);
+
+        let q = array![0.25, 0.25, 0.25, 0.25];
+        let d = kl_divergence(&p, &q);
+        assert_relative_eq!(renyi_divergence(&p, &q, 1.0 - 1e-7), d, epsilon = 1e-6);
+        assert_relative_eq!(renyi_divergence(&p, &q, 1.0 + 1e-7), d, epsilon = 1e-6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_negative_order() {
+        let _ = renyi_entropy(&array![0.5, 0.5], -1.0);
+    }
+
+    #[test]
+    fn test_infinite_divergence() {
+        let p = array![0.5, 0.5];
+        let q = array![1.0, 0.0];
+        assert_eq!(renyi_divergence(&p, &q, 2.0), f64::INFINITY);
+        assert_eq!(renyi_divergence(&p, &q, f64::INFINITY), f64::INFINITY);
+        assert!(renyi_divergence(&p, &q, 0.5).is_finite());
+    }
+
+    #[test]
+    /// https://en.wikipedia.org/wiki/R%C3%A9nyi_entropy#Inequalities_for_different_orders_%CE%B1
+    fn test_monotonic_orders() {
+        for _ in 0..N_ITER {
+            let c_p = Array1::random(ARRAY_SIZE, Uniform::new(0.1, 0.9));
+            let p = &c_p / c_p.sum();
+            let c_q = Array1::random(ARRAY_SIZE, Uniform::new(0.1, 0.9));
+            let q = &c_q / c_q.sum();
+            for pair in ORDERS.windows(2) {
+                // Measures: Hα is non-increasing in α
+                assert!(renyi_entropy(&p, pair[1]) <= renyi_entropy(&p, pair[0]) + EPSILON);
+
+                // Measures: Dα is non-decreasing in α
+                assert!(
+                    renyi_divergence(&p, &q, pair[0])
+                        <= renyi_divergence(&p, &q, pair[1]) + EPSILON
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mutual_information() {
+        for _ in 0..N_ITER {
+            let c_xy = Array2::random((4, ARRAY_SIZE), Uniform::new(0.1, 0.9));
+            let p_xy = &c_xy / c_xy.sum();
+            assert_relative_eq!(
+                renyi_mutual_information(&p_xy, 1.0),
+                mutual_information(&p_xy),
+                epsilon = EPSILON
+            );
+            assert!(renyi_mutual_information(&p_xy, 0.5) >= -EPSILON);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::hist::{hist1d, hist2d, hist3d, histnd};
+use anyhow::Result;
+use ndarray::{Array, Array1, Array2, Array3, ArrayD, Dimension};
+
+/// # Shrinkage Intensity
+/// <https://jmlr.org/papers/v10/hausser09a.html>
+///
+/// Calculates the data-driven James–Stein shrinkage intensity `λ` of Hausser and Strimmer (2009)
+/// for a histogram of event counts of any dimension.
+///
+/// The target is the uniform distribution over all `K` cells, `t = 1 / K`, and the intensity
+/// is calculated from the maximum likelihood frequencies `θ = n / N` as follows:
+/// ```math
+/// λ = (1 - Σ θ²) / ((N - 1) * Σ (t - θ)²)
+/// ```
+///
+/// The intensity is clipped to `[0, 1]`, and is one when there are fewer than two events.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::shrinkage_intensity;
+///
+/// // already uniform, so the shrinkage has no effect
+/// assert_eq!(shrinkage_intensity(&array![5, 5, 5, 5]), 1.0);
+///
+/// let lambda = shrinkage_intensity(&array![1, 0, 3, 0]);
+/// assert!(lambda > 0.0 && lambda < 1.0);
+/// ```
+#[must_use]
+pub fn shrinkage_intensity<D: Dimension>(counts: &Array<usize, D>) -> f64 {
+    let total = counts.sum() as f64;
+    if total < 2.0 {
+        return 1.0;
+    }
+    let target = 1.0 / counts.len() as f64;
+    let (sum_sq, sum_diff) = counts.iter().fold((0.0, 0.0), |(sq, diff), c| {
+        let theta = *c as f64 / total;
+        (sq + theta * theta, diff + (target - theta).powi(2))
+    });
+    if sum_diff == 0.0 {
+        return 1.0;
+    }
+    ((1.0 - sum_sq) / ((total - 1.0) * sum_diff)).clamp(0.0, 1.0)
+}
+
+/// # Shrinkage Probability
+/// Calculates the James–Stein shrinkage probabilities of a histogram of event counts of any
+/// dimension, keeping its shape.
+///
+/// Each cell is a convex combination of the uniform target and the maximum likelihood frequency
+/// weighted by the [`shrinkage_intensity`]:
+/// ```math
+/// θ_shrink = λ * t + (1 - λ) * θ
+/// ```
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::shrink_hist;
+///
+/// let prob = shrink_hist(&array![[3, 1], [0, 0]]);
+/// assert_eq!(prob.shape(), &[2, 2]);
+/// assert!((prob.sum() - 1.0).abs() < 1e-12);
+/// assert!(prob[(1, 1)] > 0.0);
+/// ```
+#[must_use]
+pub fn shrink_hist<D: Dimension>(counts: &Array<usize, D>) -> Array<f64, D> {
+    let lambda = shrinkage_intensity(counts);
+    let total = counts.sum() as f64;
+    let target = 1.0 / counts.len() as f64;
+    counts.mapv(|c| {
+        let theta = if total == 0.0 { 0.0 } else { c as f64 / total };
+        lambda * target + (1.0 - lambda) * theta
+    })
+}
+
+/// Calculates the James–Stein shrinkage probability of events in each bin for a single integer
+/// array
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{prob1d, shrink_prob1d};
+///
+/// let arr = array![0, 0, 0, 1];
+/// let prob = shrink_prob1d(&arr, 4).unwrap();
+/// let raw = prob1d(&arr, 4).unwrap();
+/// assert!(prob[0] < raw[0]);
+/// assert!(prob[3] > 0.0);
+/// ```
+pub fn shrink_prob1d(arr: &Array1<usize>, nbins: usize) -> Result<Array1<f64>> {
+    Ok(shrink_hist(&hist1d(arr, nbins)?))
+}
+
+/// Calculates the James–Stein shrinkage event intersection probability between two arrays of
+/// equal size
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{mutual_information, shrink_prob2d};
+///
+/// let arr_a = array![0, 1, 1, 0];
+/// let arr_b = array![0, 1, 0, 1];
+/// let prob = shrink_prob2d(&arr_a, &arr_b, 2, 2).unwrap();
+/// assert_eq!(prob.shape(), &[2, 2]);
+/// assert!(mutual_information(&prob) >= 0.0);
+/// ```
+pub fn shrink_prob2d(
+    arr_a: &Array1<usize>,
+    arr_b: &Array1<usize>,
+    nbins_a: usize,
+    nbins_b: usize,
+) -> Result<Array2<f64>> {
+    Ok(shrink_hist(&hist2d(arr_a, arr_b, nbins_a, nbins_b)?))
+}
+
+/// Calculates the James–Stein shrinkage event intersection probability between three arrays of
+/// equal size
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{conditional_mutual_information, shrink_prob3d};
+///
+/// let arr_a = array![0, 1, 1, 0];
+/// let arr_b = array![0, 1, 0, 1];
+/// let arr_c = array![0, 0, 1, 1];
+/// let prob = shrink_prob3d(&arr_a, &arr_b, &arr_c, 2, 2, 2).unwrap();
+/// assert_eq!(prob.shape(), &[2, 2, 2]);
+/// assert!(conditional_mutual_information(&prob) >= 0.0);
+/// ```
+pub fn shrink_prob3d(
+    arr_a: &Array1<usize>,
+    arr_b: &Array1<usize>,
+    arr_c: &Array1<usize>,
+    nbins_a: usize,
+    nbins_b: usize,
+    nbins_c: usize,
+) -> Result<Array3<f64>> {
+    Ok(shrink_hist(&hist3d(
+        arr_a, arr_b, arr_c, nbins_a, nbins_b, nbins_c,
+    )?))
+}
+
+/// Calculates the James–Stein shrinkage event intersection probability between an arbitrary
+/// number of arrays of equal size
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::shrink_probnd;
+///
+/// let arr_a = array![0, 1, 1, 0];
+/// let arr_b = array![0, 1, 0, 1];
+/// let prob = shrink_probnd(&[arr_a.clone(), arr_b, arr_a], &[2, 2, 2]).unwrap();
+/// assert_eq!(prob.shape(), &[2, 2, 2]);
+/// ```
+pub fn shrink_probnd(arrs: &[Array1<usize>], nbins: &[usize]) -> Result<ArrayD<f64>> {
+    Ok(shrink_hist(&histnd(arrs, nbins)?))
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{shrink_hist, shrink_prob1d, shrink_prob2d, shrinkage_intensity};
+    use crate::{mutual::mutual_information, prob::prob2d};
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    const N_ITER: usize = 500;
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn test_reference_value() {
+        // θ = [0.25, 0, 0.75, 0], t = 0.25
+        // λ = (1 - 0.625) / (3 * 0.375) = 1 / 3
+        let counts = array![1, 0, 3, 0];
+        assert_relative_eq!(shrinkage_intensity(&counts), 1.0 / 3.0, epsilon = EPSILON);
+        let expected = array![0.25, 1.0 / 12.0, 7.0 / 12.0, 1.0 / 12.0];
+        for (p, e) in shrink_hist(&counts).iter().zip(expected.iter()) {
+            assert_relative_eq!(p, e, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(shrinkage_intensity(&array![0, 0, 0]), 1.0);
+        assert_eq!(shrinkage_intensity(&array![0, 1, 0]), 1.0);
+        assert_eq!(shrink_hist(&array![0, 0]), array![0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_normalized() {
+        for _ in 0..N_ITER {
+            let x = Array1::random(20, Uniform::new(0, 10));
+            let y = Array1::random(20, Uniform::new(0, 10));
+            let p_x = shrink_prob1d(&x, 10).unwrap();
+            let p_xy = shrink_prob2d(&x, &y, 10, 10).unwrap();
+            assert_relative_eq!(p_x.sum(), 1.0, epsilon = EPSILON);
+            assert_relative_eq!(p_xy.sum(), 1.0, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    /// Independent variables have zero mutual information, which the shrinkage estimate should
+    /// approach more closely than the plug-in on small samples
+    fn test_independent_mutual_information() {
+        let mut i_plugin = 0.0;
+        let mut i_shrink = 0.0;
+        for _ in 0..N_ITER {
+            let x = Array1::random(30, Uniform::new(0, 5));
+            let y = Array1::random(30, Uniform::new(0, 5));
+            i_plugin += mutual_information(&prob2d(&x, &y, 5, 5).unwrap());
+            i_shrink += mutual_information(&shrink_prob2d(&x, &y, 5, 5).unwrap());
+        }
+        assert!(i_shrink < i_plugin);
+    }
+}
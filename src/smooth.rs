@@ -0,0 +1,248 @@
+use crate::hist::{hist1d, hist2d, hist3d, histnd};
+use anyhow::{bail, Result};
+use ndarray::{Array, Array1, Array2, Array3, ArrayD, Dimension, Ix1, Ix2, Ix3, IxDyn, Zip};
+
+/// # Pseudocount
+/// <https://en.wikipedia.org/wiki/Additive_smoothing>
+///
+/// Dirichlet prior added to every bin of a histogram before it is normalized.
+///
+/// * [`Laplace`](Pseudocount::Laplace): one event per bin.
+/// * [`Jeffreys`](Pseudocount::Jeffreys): one half event per bin.
+/// * [`Perks`](Pseudocount::Perks): `1 / K` events per bin where `K` is the number of bins.
+/// * [`Custom`](Pseudocount::Custom): an array of pseudocounts with the same shape as the
+///   histogram.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pseudocount<D: Dimension> {
+    Laplace,
+    Jeffreys,
+    Perks,
+    Custom(Array<f64, D>),
+}
+
+/// Calculates the smoothed probability of each bin of a histogram of event counts of any
+/// dimension, keeping its shape.
+///
+/// ```math
+/// p(x) = (n(x) + a(x)) / (N + Σ a(x))
+/// ```
+///
+/// # Errors
+/// If a custom prior does not match the shape of the histogram, contains negative or non-finite
+/// values, or if the smoothed histogram is empty.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{smooth_hist, Pseudocount};
+///
+/// let counts = array![2, 0, 0, 0];
+/// let prob = smooth_hist(&counts, &Pseudocount::Laplace).unwrap();
+/// assert_eq!(prob, array![0.5, 1.0 / 6.0, 1.0 / 6.0, 1.0 / 6.0]);
+///
+/// let prior = Pseudocount::Custom(array![0.0, 2.0, 0.0, 0.0]);
+/// let prob = smooth_hist(&counts, &prior).unwrap();
+/// assert_eq!(prob, array![0.5, 0.5, 0.0, 0.0]);
+/// ```
+pub fn smooth_hist<D: Dimension>(
+    counts: &Array<usize, D>,
+    prior: &Pseudocount<D>,
+) -> Result<Array<f64, D>> {
+    let counts = counts.mapv(|c| c as f64);
+    let smoothed = match prior {
+        Pseudocount::Laplace => counts + 1.0,
+        Pseudocount::Jeffreys => counts + 0.5,
+        Pseudocount::Perks => {
+            let pseudocount = 1.0 / counts.len() as f64;
+            counts + pseudocount
+        }
+        Pseudocount::Custom(pseudocounts) => {
+            if pseudocounts.shape() != counts.shape() {
+                bail!("Provided pseudocounts must match the shape of the histogram");
+            }
+            if pseudocounts.iter().any(|a| !(a.is_finite() && *a >= 0.0)) {
+                bail!("Provided pseudocounts must be non-negative and finite");
+            }
+            let mut smoothed = counts;
+            Zip::from(&mut smoothed)
+                .and(pseudocounts)
+                .for_each(|c, a| *c += a);
+            smoothed
+        }
+    };
+    let total = smoothed.sum();
+    if total <= 0.0 {
+        bail!("Smoothed histogram must contain at least one event");
+    }
+    Ok(smoothed / total)
+}
+
+/// Calculates the smoothed probability of events in each bin for a single integer array
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{smooth_prob1d, Pseudocount};
+///
+/// let arr = array![0, 0, 1];
+/// let prob = smooth_prob1d(&arr, 3, &Pseudocount::Jeffreys).unwrap();
+/// assert_eq!(prob, array![2.5 / 4.5, 1.5 / 4.5, 0.5 / 4.5]);
+/// ```
+pub fn smooth_prob1d(
+    arr: &Array1<usize>,
+    nbins: usize,
+    prior: &Pseudocount<Ix1>,
+) -> Result<Array1<f64>> {
+    smooth_hist(&hist1d(arr, nbins)?, prior)
+}
+
+/// Calculates the smoothed event intersection probability between two arrays of equal size
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{smooth_prob2d, Pseudocount};
+///
+/// let arr_a = array![0, 1];
+/// let arr_b = array![0, 1];
+/// let expected = array![[0.375, 0.125],
+///                       [0.125, 0.375]];
+/// let prob = smooth_prob2d(&arr_a, &arr_b, 2, 2, &Pseudocount::Jeffreys).unwrap();
+/// assert_eq!(prob, expected);
+/// ```
+pub fn smooth_prob2d(
+    arr_a: &Array1<usize>,
+    arr_b: &Array1<usize>,
+    nbins_a: usize,
+    nbins_b: usize,
+    prior: &Pseudocount<Ix2>,
+) -> Result<Array2<f64>> {
+    smooth_hist(&hist2d(arr_a, arr_b, nbins_a, nbins_b)?, prior)
+}
+
+/// Calculates the smoothed event intersection probability between three arrays of equal size
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{smooth_prob3d, Pseudocount};
+///
+/// let arr_a = array![0, 1];
+/// let arr_b = array![0, 1];
+/// let arr_c = array![0, 1];
+/// let prob = smooth_prob3d(&arr_a, &arr_b, &arr_c, 2, 2, 2, &Pseudocount::Perks).unwrap();
+/// assert_eq!(prob.shape(), &[2, 2, 2]);
+/// assert!(prob.iter().all(|p| *p > 0.0));
+/// ```
+pub fn smooth_prob3d(
+    arr_a: &Array1<usize>,
+    arr_b: &Array1<usize>,
+    arr_c: &Array1<usize>,
+    nbins_a: usize,
+    nbins_b: usize,
+    nbins_c: usize,
+    prior: &Pseudocount<Ix3>,
+) -> Result<Array3<f64>> {
+    smooth_hist(
+        &hist3d(arr_a, arr_b, arr_c, nbins_a, nbins_b, nbins_c)?,
+        prior,
+    )
+}
+
+/// Calculates the smoothed event intersection probability between an arbitrary number of arrays
+/// of equal size
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{smooth_probnd, Pseudocount};
+///
+/// let arr_a = array![0, 1];
+/// let arr_b = array![0, 1];
+/// let prob = smooth_probnd(&[arr_a, arr_b], &[2, 2], &Pseudocount::Laplace).unwrap();
+/// assert_eq!(prob.shape(), &[2, 2]);
+/// assert_eq!(prob[[0, 1]], 0.25 / 1.5);
+/// ```
+pub fn smooth_probnd(
+    arrs: &[Array1<usize>],
+    nbins: &[usize],
+    prior: &Pseudocount<IxDyn>,
+) -> Result<ArrayD<f64>> {
+    smooth_hist(&histnd(arrs, nbins)?, prior)
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{smooth_hist, smooth_prob2d, Pseudocount};
+    use crate::prob::prob2d;
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1, Array2};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn test_perks() {
+        let counts = array![[1, 0], [0, 0]];
+        let prob = smooth_hist(&counts, &Pseudocount::Perks).unwrap();
+        assert_eq!(prob, array![[0.625, 0.125], [0.125, 0.125]]);
+    }
+
+    #[test]
+    fn test_custom_zero_prior() {
+        let x = Array1::random(100, Uniform::new(0, 3));
+        let y = Array1::random(100, Uniform::new(0, 3));
+        let prior = Pseudocount::Custom(Array2::zeros((3, 3)));
+        let smoothed = smooth_prob2d(&x, &y, 3, 3, &prior).unwrap();
+        let raw = prob2d(&x, &y, 3, 3).unwrap();
+        for (s, r) in smoothed.iter().zip(raw.iter()) {
+            assert_relative_eq!(s, r, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_no_zero_cells() {
+        let x = Array1::random(5, Uniform::new(0, 10));
+        let y = Array1::random(5, Uniform::new(0, 10));
+        for prior in [
+            Pseudocount::Laplace,
+            Pseudocount::Jeffreys,
+            Pseudocount::Perks,
+        ] {
+            let prob = smooth_prob2d(&x, &y, 10, 10, &prior).unwrap();
+            assert_relative_eq!(prob.sum(), 1.0, epsilon = EPSILON);
+            assert!(prob.iter().all(|p| *p > 0.0));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_custom_shape_mismatch() {
+        let counts = array![1, 2, 3];
+        smooth_hist(&counts, &Pseudocount::Custom(array![1.0, 1.0])).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_custom_negative() {
+        let counts = array![1, 2, 3];
+        smooth_hist(&counts, &Pseudocount::Custom(array![1.0, -1.0, 1.0])).unwrap();
+    }
+
+    #[test]
+    fn test_custom_non_finite() {
+        let counts = array![1, 2, 3];
+        for a in [f64::NAN, f64::INFINITY] {
+            let prior = Pseudocount::Custom(array![1.0, a, 1.0]);
+            assert!(smooth_hist(&counts, &prior).is_err());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty() {
+        let counts = array![0, 0, 0];
+        smooth_hist(&counts, &Pseudocount::Custom(array![0.0, 0.0, 0.0])).unwrap();
+    }
+}
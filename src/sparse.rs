@@ -0,0 +1,281 @@
+use crate::{
+    cmi::ConditionalMutualInformation, conditional::ConditionalEntropy, entropy::Entropy,
+    mutual::MutualInformation,
+};
+use anyhow::{bail, Result};
+use ndarray::Array1;
+use std::collections::HashMap;
+
+/// # Sparse Histogram
+/// Joint event counts of `N` integer variables keyed by their index tuple.
+///
+/// Only the combinations which were actually observed are stored, so memory scales with the
+/// number of distinct observations rather than the product of the alphabet sizes as it does for
+/// [`hist2d`](crate::hist2d) and [`hist3d`](crate::hist3d).
+///
+/// It can be passed directly to [`entropy()`](crate::entropy()),
+/// [`conditional_entropy()`](crate::conditional_entropy()),
+/// [`mutual_information()`](crate::mutual_information()) and
+/// [`conditional_mutual_information()`](crate::conditional_mutual_information()),
+/// which normalize the counts internally.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{sparse_hist, SparseHist};
+///
+/// let mut hist = SparseHist::new();
+/// hist.insert([0, 40_000]);
+/// hist.insert([0, 40_000]);
+/// hist.insert([70_000, 3]);
+///
+/// assert_eq!(hist.get(&[0, 40_000]), 2);
+/// assert_eq!(hist.get(&[1, 1]), 0);
+/// assert_eq!(hist.total(), 3);
+/// assert_eq!(hist.len(), 2);
+///
+/// let arr_a = array![0, 0, 70_000];
+/// let arr_b = array![40_000, 40_000, 3];
+/// assert_eq!(sparse_hist([&arr_a, &arr_b]).unwrap(), hist);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SparseHist<const N: usize> {
+    counts: HashMap<[usize; N], usize>,
+    total: usize,
+}
+
+impl<const N: usize> SparseHist<N> {
+    /// Creates an empty histogram
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+            total: 0,
+        }
+    }
+
+    /// Records a single event at the provided index tuple
+    pub fn insert(&mut self, index: [usize; N]) {
+        *self.counts.entry(index).or_insert(0) += 1;
+        self.total += 1;
+    }
+
+    /// Returns the number of events recorded at the provided index tuple
+    #[must_use]
+    pub fn get(&self, index: &[usize; N]) -> usize {
+        self.counts.get(index).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of events recorded
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the number of distinct index tuples observed
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns `true` if no events have been recorded
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Iterates over the observed index tuples and their counts
+    pub fn iter(&self) -> impl Iterator<Item = (&[usize; N], &usize)> {
+        self.counts.iter()
+    }
+
+    /// Sums the counts over all axes except the provided ones, keeping them in the order given
+    ///
+    /// # Panics
+    /// If any of the provided axes is out of bounds.
+    #[must_use]
+    pub fn marginal<const M: usize>(&self, axes: [usize; M]) -> SparseHist<M> {
+        let mut marginal = SparseHist::new();
+        for (index, count) in self.iter() {
+            let key = axes.map(|axis| index[axis]);
+            *marginal.counts.entry(key).or_insert(0) += count;
+        }
+        marginal.total = self.total;
+        marginal
+    }
+}
+
+/// Calculates the sparse event intersection between `N` integer arrays of equal size
+///
+/// Unlike [`histnd`](crate::histnd) no bin counts are required, as only the observed combinations
+/// are stored.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::sparse_hist;
+///
+/// let arr_a = array![0, 1, 1, 1, 2, 2];
+/// let arr_b = array![1, 0, 0, 1, 2, 3];
+/// let hist = sparse_hist([&arr_a, &arr_b]).unwrap();
+/// assert_eq!(hist.get(&[1, 0]), 2);
+/// assert_eq!(hist.get(&[0, 0]), 0);
+/// assert_eq!(hist.len(), 5);
+/// ```
+pub fn sparse_hist<const N: usize>(arrs: [&Array1<usize>; N]) -> Result<SparseHist<N>> {
+    if N == 0 {
+        bail!("At least one array must be provided");
+    }
+    let size = arrs[0].len();
+    if arrs.iter().any(|arr| arr.len() != size) {
+        bail!("Provided arrays must be of equal size");
+    }
+    let mut events = SparseHist::new();
+    for idx in 0..size {
+        events.insert(arrs.map(|arr| arr[idx]));
+    }
+    Ok(events)
+}
+
+impl<const N: usize> Entropy for SparseHist<N> {
+    fn entropy(&self) -> f64 {
+        let total = self.total as f64;
+        self.counts.values().fold(0.0, |acc, c| {
+            let p = *c as f64 / total;
+            acc - (p * p.ln())
+        })
+    }
+}
+
+impl ConditionalEntropy for SparseHist<2> {
+    fn conditional_entropy(&self) -> f64 {
+        let total = self.total as f64;
+        let c_y = self.marginal([1]);
+        self.iter().fold(0.0, |acc, ([_, y], xy)| {
+            let xy = *xy as f64;
+            let y = c_y.get(&[*y]) as f64;
+            acc - ((xy / total) * (xy / y).ln())
+        })
+    }
+}
+
+impl MutualInformation for SparseHist<2> {
+    fn mutual_information(&self) -> f64 {
+        let total = self.total as f64;
+        let c_x = self.marginal([0]);
+        let c_y = self.marginal([1]);
+        self.iter().fold(0.0, |acc, ([x, y], xy)| {
+            let xy = *xy as f64;
+            let x = c_x.get(&[*x]) as f64;
+            let y = c_y.get(&[*y]) as f64;
+            acc + ((xy / total) * ((xy * total) / (x * y)).ln())
+        })
+    }
+}
+
+impl ConditionalMutualInformation for SparseHist<3> {
+    fn conditional_mutual_information(&self) -> f64 {
+        let total = self.total as f64;
+        let c_xz = self.marginal([0, 2]);
+        let c_yz = self.marginal([1, 2]);
+        let c_z = self.marginal([2]);
+        self.iter().fold(0.0, |acc, ([x, y, z], xyz)| {
+            let xyz = *xyz as f64;
+            let xz = c_xz.get(&[*x, *z]) as f64;
+            let yz = c_yz.get(&[*y, *z]) as f64;
+            let z = c_z.get(&[*z]) as f64;
+            acc + ((xyz / total) * ((z * xyz) / (xz * yz)).ln())
+        })
+    }
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{sparse_hist, SparseHist};
+    use crate::{
+        cmi::conditional_mutual_information,
+        conditional::conditional_entropy,
+        entropy::entropy,
+        mutual::mutual_information,
+        prob::{prob1d, prob2d, prob3d},
+    };
+    use approx::assert_relative_eq;
+    use ndarray::{array, Array1};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    const N_ITER: usize = 100;
+    const ARRAY_SIZE: usize = 100;
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn test_empty() {
+        let hist = SparseHist::<2>::new();
+        assert!(hist.is_empty());
+        assert_eq!(entropy(&hist), 0.0);
+        assert_eq!(conditional_entropy(&hist), 0.0);
+        assert_eq!(mutual_information(&hist), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unequal() {
+        let arr_a = array![0, 1, 1];
+        let arr_b = array![0, 1];
+        sparse_hist([&arr_a, &arr_b]).unwrap();
+    }
+
+    #[test]
+    fn test_marginal() {
+        let arr_a = array![0, 1, 1, 2];
+        let arr_b = array![3, 3, 4, 3];
+        let hist = sparse_hist([&arr_a, &arr_b]).unwrap();
+        let marginal = hist.marginal([1]);
+        assert_eq!(marginal.get(&[3]), 3);
+        assert_eq!(marginal.get(&[4]), 1);
+        assert_eq!(marginal.total(), 4);
+    }
+
+    #[test]
+    fn test_high_cardinality() {
+        let arr_a = array![0, 1_000_000, 1_000_000, 0];
+        let arr_b = array![5_000_000, 7, 7, 5_000_000];
+        let hist = sparse_hist([&arr_a, &arr_b]).unwrap();
+        assert_relative_eq!(mutual_information(&hist), 2.0_f64.ln());
+        assert_relative_eq!(conditional_entropy(&hist), 0.0);
+    }
+
+    #[test]
+    fn test_dense_agreement() {
+        for _ in 0..N_ITER {
+            let x = Array1::random(ARRAY_SIZE, Uniform::new(0, 4));
+            let y = Array1::random(ARRAY_SIZE, Uniform::new(0, 4));
+            let z = Array1::random(ARRAY_SIZE, Uniform::new(0, 4));
+
+            let s_x = sparse_hist([&x]).unwrap();
+            let s_xy = sparse_hist([&x, &y]).unwrap();
+            let s_xyz = sparse_hist([&x, &y, &z]).unwrap();
+
+            let p_x = prob1d(&x, 4).unwrap();
+            let p_xy = prob2d(&x, &y, 4, 4).unwrap();
+            let p_xyz = prob3d(&x, &y, &z, 4, 4, 4).unwrap();
+
+            assert_relative_eq!(entropy(&s_x), entropy(&p_x), epsilon = EPSILON);
+            assert_relative_eq!(
+                conditional_entropy(&s_xy),
+                conditional_entropy(&p_xy),
+                epsilon = EPSILON
+            );
+            assert_relative_eq!(
+                mutual_information(&s_xy),
+                mutual_information(&p_xy),
+                epsilon = EPSILON
+            );
+            assert_relative_eq!(
+                conditional_mutual_information(&s_xyz),
+                conditional_mutual_information(&p_xyz),
+                epsilon = EPSILON
+            );
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Special functions shared by the estimators
+
+use std::f64::consts::PI;
+
+/// Digamma function `ψ(x)` for positive `x`
+///
+/// Uses the recurrence `ψ(x) = ψ(x + 1) - 1/x` to shift the argument above ten and then the
+/// asymptotic expansion.
+pub(crate) fn digamma(x: f64) -> f64 {
+    let mut x = x;
+    let mut result = 0.0;
+    while x < 10.0 {
+        result -= 1.0 / x;
+        x += 1.0;
+    }
+    let f = 1.0 / (x * x);
+    result + x.ln()
+        - 0.5 / x
+        - f * (1.0 / 12.0 - f * (1.0 / 120.0 - f * (1.0 / 252.0 - f * (1.0 / 240.0 - f / 132.0))))
+}
+
+/// Trigamma function `ψ1(x)` for positive `x`
+///
+/// Uses the recurrence `ψ1(x) = ψ1(x + 1) + 1/x²` to shift the argument above ten and then the
+/// asymptotic expansion.
+pub(crate) fn trigamma(x: f64) -> f64 {
+    let mut x = x;
+    let mut result = 0.0;
+    while x < 10.0 {
+        result += 1.0 / (x * x);
+        x += 1.0;
+    }
+    let f = 1.0 / (x * x);
+    result
+        + 1.0 / x
+        + f / 2.0
+        + f / x
+            * (1.0 / 6.0 - f * (1.0 / 30.0 - f * (1.0 / 42.0 - f * (1.0 / 30.0 - f * 5.0 / 66.0))))
+}
+
+/// Natural logarithm of the gamma function `ln[ Γ(x) ]` for positive `x`
+///
+/// Uses the Lanczos approximation (`g = 7`) and the reflection formula below one half.
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + G + 0.5;
+    let series = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(COEFFICIENTS[0], |acc, (idx, c)| acc + c / (x + idx as f64));
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+/// Quantile function (inverse cumulative distribution) of the standard normal for `p` in `(0, 1)`
+///
+/// Uses Acklam's rational approximation, with a relative error below `1.2e-9`.
+pub(crate) fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    // rational approximation of the tail, mirrored for the upper tail
+    let tail = |p: f64| {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail(p)
+    } else if p > 1.0 - P_LOW {
+        -tail(1.0 - p)
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::{digamma, ln_gamma, normal_quantile, trigamma};
+    use approx::assert_relative_eq;
+    use std::f64::consts::{LN_2, PI};
+
+    const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+    #[test]
+    fn test_digamma() {
+        assert_relative_eq!(digamma(1.0), -EULER_GAMMA, epsilon = 1e-12);
+        assert_relative_eq!(digamma(0.5), -EULER_GAMMA - 2.0 * LN_2, epsilon = 1e-12);
+        assert_relative_eq!(digamma(10.0), 2.251_752_589_066_721, epsilon = 1e-12);
+        assert_relative_eq!(digamma(1e-3), -1_000.575_571_931_81, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_trigamma() {
+        assert_relative_eq!(trigamma(1.0), PI * PI / 6.0, epsilon = 1e-12);
+        assert_relative_eq!(trigamma(0.5), PI * PI / 2.0, epsilon = 1e-12);
+        assert_relative_eq!(trigamma(25.0), 0.040_810_663_257_225_6, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        assert_relative_eq!(ln_gamma(1.0), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(ln_gamma(0.5), PI.sqrt().ln(), epsilon = 1e-12);
+        assert_relative_eq!(ln_gamma(10.0), 362_880_f64.ln(), epsilon = 1e-12);
+        assert_relative_eq!(ln_gamma(1e-6), 13.815_509_980_749_4, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_normal_quantile() {
+        assert_eq!(normal_quantile(0.5), 0.0);
+        assert_relative_eq!(
+            normal_quantile(0.975),
+            1.959_963_984_540_054,
+            epsilon = 1e-8
+        );
+        assert_relative_eq!(
+            normal_quantile(0.01),
+            -2.326_347_874_040_841,
+            epsilon = 1e-8
+        );
+        assert_relative_eq!(
+            normal_quantile(1e-10),
+            -6.361_340_889_697_422,
+            epsilon = 1e-7
+        );
+        for p in [0.001, 0.02, 0.3, 0.6, 0.99] {
+            assert_relative_eq!(
+                normal_quantile(p),
+                -normal_quantile(1.0 - p),
+                epsilon = 1e-8
+            );
+        }
+    }
+}
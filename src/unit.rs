@@ -0,0 +1,149 @@
+use std::f64::consts::{LN_10, LN_2};
+
+/// # Unit
+/// <https://en.wikipedia.org/wiki/Units_of_information>
+///
+/// Unit of information, determined by the base of the logarithm.
+///
+/// * [`Nats`](Unit::Nats): natural logarithm, which every function uses by default.
+/// * [`Bits`](Unit::Bits): base 2 logarithm (shannons).
+/// * [`Bans`](Unit::Bans): base 10 logarithm (hartleys).
+/// * [`Base`](Unit::Base): an arbitrary logarithm base.
+///
+/// # Usage
+/// ```
+/// use ndarray::array;
+/// use information::{entropy, entropy_in, joint_entropy, Unit};
+///
+/// let p = array![0.5, 0.5];
+/// assert_eq!(entropy_in(&p, Unit::Bits), 1.0);
+/// assert_eq!(entropy_in(&p, Unit::Nats), entropy(&p));
+///
+/// let p_xy = array![[0.25, 0.25], [0.25, 0.25]];
+/// assert_eq!(joint_entropy!(&p_xy, Unit::Bits), 2.0);
+///
+/// // converting an existing measurement
+/// assert_eq!(Unit::Bits.convert(entropy(&p)), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Unit {
+    #[default]
+    Nats,
+    Bits,
+    Bans,
+    Base(f64),
+}
+
+impl Unit {
+    /// Returns the natural logarithm of the base of the unit
+    ///
+    /// # Panics
+    /// If an arbitrary base is not positive, is one or is not finite.
+    #[must_use]
+    pub fn ln_base(&self) -> f64 {
+        match self {
+            Self::Nats => 1.0,
+            Self::Bits => LN_2,
+            Self::Bans => LN_10,
+            Self::Base(base) => {
+                assert!(
+                    *base > 0.0 && *base != 1.0 && base.is_finite(),
+                    "The logarithm base must be positive, finite and not equal to one"
+                );
+                base.ln()
+            }
+        }
+    }
+
+    /// Converts a value measured in nats into this unit
+    ///
+    /// # Panics
+    /// If an arbitrary base is not positive, is one or is not finite.
+    #[must_use]
+    pub fn convert(&self, nats: f64) -> f64 {
+        match self {
+            Self::Nats => nats,
+            _ => nats / self.ln_base(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod testing {
+
+    use super::Unit;
+    use crate::{
+        cmi::conditional_mutual_information_in,
+        conditional::conditional_entropy_in,
+        entropy::entropy_in,
+        joint_entropy,
+        mutual::mutual_information_in,
+        prob::{prob1d, prob2d, prob3d},
+    };
+    use approx::assert_relative_eq;
+    use ndarray::{Array1, Array2};
+    use ndarray_rand::{rand_distr::Uniform, RandomExt};
+
+    const N_ITER: usize = 100;
+    const ARRAY_SIZE: usize = 100;
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn test_uniform() {
+        let p_x = Array1::from_elem(10, 0.1);
+        assert_relative_eq!(entropy_in(&p_x, Unit::Bans), 1.0, epsilon = EPSILON);
+        assert_relative_eq!(entropy_in(&p_x, Unit::Base(10.0)), 1.0, epsilon = EPSILON);
+
+        let p_xy = Array2::from_elem((4, 4), 1.0 / 16.0);
+        assert_relative_eq!(joint_entropy!(&p_xy, Unit::Bits), 4.0, epsilon = EPSILON);
+        assert_relative_eq!(
+            joint_entropy!(&p_xy, Unit::Base(4.0)),
+            2.0,
+            epsilon = EPSILON
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_base() {
+        let _ = Unit::Base(1.0).convert(1.0);
+    }
+
+    #[test]
+    /// Every measure should convert between units by the same change of base
+    fn test_conversions_agree() {
+        for _ in 0..N_ITER {
+            let x = Array1::random(ARRAY_SIZE, Uniform::new(0, 3));
+            let y = Array1::random(ARRAY_SIZE, Uniform::new(0, 3));
+            let z = Array1::random(ARRAY_SIZE, Uniform::new(0, 3));
+            let p_x = prob1d(&x, 3).unwrap();
+            let p_xy = prob2d(&x, &y, 3, 3).unwrap();
+            let p_xyz = prob3d(&x, &y, &z, 3, 3, 3).unwrap();
+
+            let measures = |unit: Unit| {
+                [
+                    entropy_in(&p_x, unit),
+                    joint_entropy!(&p_xyz, unit),
+                    conditional_entropy_in(&p_xy, unit),
+                    mutual_information_in(&p_xy, unit),
+                    conditional_mutual_information_in(&p_xyz, unit),
+                ]
+            };
+            let nats = measures(Unit::Nats);
+            let bits = measures(Unit::Bits);
+            let bans = measures(Unit::Bans);
+            let base = measures(Unit::Base(3.0));
+
+            for idx in 0..nats.len() {
+                // Measures: log2(x) = ln(x) / ln(2)
+                assert_relative_eq!(bits[idx], nats[idx] / 2_f64.ln(), epsilon = EPSILON);
+
+                // Measures: log10(x) = log2(x) * log10(2)
+                assert_relative_eq!(bans[idx], bits[idx] * 2_f64.log10(), epsilon = EPSILON);
+
+                // Measures: log3(x) = log10(x) / log10(3)
+                assert_relative_eq!(base[idx], bans[idx] / 3_f64.log10(), epsilon = EPSILON);
+            }
+        }
+    }
+}